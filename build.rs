@@ -0,0 +1,21 @@
+use std::env;
+use std::process::Command;
+
+// `#[diagnostic::on_unimplemented]` is stable since Rust 1.78. Older compilers reject the
+// `diagnostic` namespace, so the attributes are only emitted when `ov_diagnostic` is set.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(ov_diagnostic)");
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let minor = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|version| version.split('.').nth(1)?.parse::<u32>().ok());
+
+    if matches!(minor, Some(minor) if minor >= 78) {
+        println!("cargo:rustc-cfg=ov_diagnostic");
+    }
+}
@@ -0,0 +1,141 @@
+//! Alternative names for the traits, as used by other crates.
+//!
+//! These make it possible to migrate from crates such as `tap` or `apply` one file at a
+//! time. Each method behaves exactly like the `Over` method named in its documentation.
+
+use core::ops::{Deref, DerefMut};
+
+/// Alias of [`Over::over`](trait.Over.html)
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(5.pipe(|n| n + 1), 5.over(|n| n + 1));
+/// ```
+pub trait Pipe: Sized {
+    fn pipe<F, Ret>(self, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        f(self)
+    }
+}
+
+impl<T> Pipe for T {}
+
+/// Alias of [`OverRef::over_ref`](trait.OverRef.html)
+pub trait PipeRef {
+    fn pipe_ref<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(&Self) -> Ret,
+    {
+        f(self)
+    }
+}
+
+impl<T> PipeRef for T {}
+
+/// Alias of [`OverMut::over_mut`](trait.OverMut.html)
+pub trait PipeMut {
+    fn pipe_mut<F, Ret>(&mut self, f: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        f(self)
+    }
+}
+
+impl<T> PipeMut for T {}
+
+/// Alias of [`OverDeref::over_deref`](trait.OverDeref.html)
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `Deref`, so it can't be passed to `pipe_deref`",
+        label = "not `Deref`",
+        note = "use `pipe_ref` to pass a reference to the value itself"
+    )
+)]
+pub trait PipeDeref: Deref {
+    fn pipe_deref<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(&<Self as Deref>::Target) -> Ret;
+}
+
+impl<T> PipeDeref for T
+where
+    T: Deref,
+{
+    fn pipe_deref<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(&Self::Target) -> Ret,
+    {
+        f(Deref::deref(self))
+    }
+}
+
+/// Alias of [`OverDerefMut::over_deref_mut`](trait.OverDerefMut.html)
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `DerefMut`, so it can't be passed to `pipe_deref_mut`",
+        label = "not `DerefMut`",
+        note = "use `pipe_mut` to pass a unique reference to the value itself"
+    )
+)]
+pub trait PipeDerefMut: DerefMut {
+    fn pipe_deref_mut<F, Ret>(&mut self, f: F) -> Ret
+    where
+        F: FnOnce(&mut Self::Target) -> Ret;
+}
+
+impl<T> PipeDerefMut for T
+where
+    T: DerefMut,
+{
+    fn pipe_deref_mut<F, Ret>(&mut self, f: F) -> Ret
+    where
+        F: FnOnce(&mut Self::Target) -> Ret,
+    {
+        f(DerefMut::deref_mut(self))
+    }
+}
+
+/// Alias of [`Over::over`](trait.Over.html), named as in the `apply` crate
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!("a".apply(str::len), 1);
+/// ```
+pub trait Apply: Sized {
+    fn apply<F, Ret>(self, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        f(self)
+    }
+}
+
+impl<T> Apply for T {}
+
+/// Alias of [`OverMut::over_mut`](trait.OverMut.html) which returns the value afterwards
+/// (`self |> { f(&mut self); self }`)
+///
+/// ```
+/// use ov::*;
+///
+/// let v = Vec::new().with(|v| v.push(1)).with(|v| v.push(2));
+/// assert_eq!(v, [1, 2]);
+/// ```
+pub trait With: Sized {
+    fn with<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        f(&mut self);
+        self
+    }
+}
+
+impl<T> With for T {}
@@ -0,0 +1,189 @@
+//! Counting the heap allocations made by a stage.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// A global allocator which counts the allocations made on each thread
+///
+/// Install it in the test binary for [`over_alloc_count`](trait.OverAllocCount.html) to see
+/// anything. Allocations, zeroed allocations and reallocations are each counted once, with
+/// the requested size.
+///
+/// ```
+/// use ov::CountingAllocator;
+///
+/// #[global_allocator]
+/// static ALLOC: CountingAllocator = CountingAllocator::new();
+/// # fn main() {}
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CountingAllocator<A = System> {
+    inner: A,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        CountingAllocator { inner: System }
+    }
+}
+
+impl<A> CountingAllocator<A> {
+    /// Count the allocations made through another allocator
+    pub const fn with(inner: A) -> Self {
+        CountingAllocator { inner }
+    }
+}
+
+fn count(size: usize) {
+    // The counters may already be gone while the thread is shutting down.
+    let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+    let _ = BYTES.try_with(|n| n.set(n.get() + size as u64));
+}
+
+fn snapshot() -> AllocCount {
+    AllocCount {
+        allocations: ALLOCATIONS.with(Cell::get),
+        bytes: BYTES.with(Cell::get),
+    }
+}
+
+unsafe impl<A> GlobalAlloc for CountingAllocator<A>
+where
+    A: GlobalAlloc,
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        self.inner.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        self.inner.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        self.inner.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout)
+    }
+}
+
+/// The heap allocations made by a stage, as counted by
+/// [`CountingAllocator`](struct.CountingAllocator.html)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocCount {
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+/// Transform by value, counting the heap allocations made by the closure
+/// (`self |> (f(self), count)`)
+///
+/// Only allocations made on the current thread are counted, so tests running in parallel
+/// don't disturb each other. Without [`CountingAllocator`](struct.CountingAllocator.html)
+/// installed as the global allocator the count is always zero.
+///
+/// ```
+/// use ov::*;
+///
+/// #[global_allocator]
+/// static ALLOC: CountingAllocator = CountingAllocator::new();
+///
+/// fn main() {
+///     let names = vec!["a".to_string(), "b".to_string()];
+///
+///     let (len, count) = names.over_alloc_count(|v| v.len());
+///     assert_eq!(len, 2);
+///     assert_eq!(count.allocations, 0);
+///
+///     let names = vec!["a".to_string(), "b".to_string()];
+///     let (copy, count) = names.over_alloc_count(|v| v.clone());
+///     assert_eq!(copy.len(), 2);
+///     assert_eq!(count.allocations, 3);
+/// }
+/// ```
+pub trait OverAllocCount: Sized {
+    fn over_alloc_count<F, Ret>(self, f: F) -> (Ret, AllocCount)
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        let before = snapshot();
+        let ret = f(self);
+        let after = snapshot();
+        let count = AllocCount {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+        };
+        (ret, count)
+    }
+}
+
+impl<T> OverAllocCount for T {}
+
+/// Transform by value, reporting when the closure allocates more than `limit`
+/// (`self |> f`)
+///
+/// The limit is exceeded when either the number of allocations or the number of bytes is
+/// over it, so a zero limit enforces that a stage doesn't allocate. A stage over its budget
+/// is reported to stderr along with the caller's location;
+/// [`over_alloc_budget_with`](#method.over_alloc_budget_with) takes a callback instead.
+///
+/// ```
+/// use ov::*;
+///
+/// #[global_allocator]
+/// static ALLOC: CountingAllocator = CountingAllocator::new();
+///
+/// fn main() {
+///     let no_alloc = AllocCount::default();
+///
+///     let mut exceeded = None;
+///     let upper = "abc".over_alloc_budget_with(no_alloc, str::to_uppercase, |count| {
+///         exceeded = Some(count)
+///     });
+///     assert_eq!(upper, "ABC");
+///     assert_eq!(exceeded.map(|count| count.allocations), Some(1));
+///
+///     let len = "abc".over_alloc_budget(no_alloc, str::len);
+///     assert_eq!(len, 3);
+/// }
+/// ```
+pub trait OverAllocBudget: Sized {
+    #[track_caller]
+    fn over_alloc_budget<F, Ret>(self, limit: AllocCount, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        let location = std::panic::Location::caller();
+        self.over_alloc_budget_with(limit, f, |count| {
+            eprintln!(
+                "ov: stage at {} made {} allocations of {} bytes, over its budget of {} allocations of {} bytes",
+                location, count.allocations, count.bytes, limit.allocations, limit.bytes
+            );
+        })
+    }
+
+    /// Like [`over_alloc_budget`](#method.over_alloc_budget), calling `on_exceed` with the
+    /// allocations made when they are over `limit`
+    fn over_alloc_budget_with<F, E, Ret>(self, limit: AllocCount, f: F, on_exceed: E) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+        E: FnOnce(AllocCount),
+    {
+        let (ret, count) = self.over_alloc_count(f);
+        if count.allocations > limit.allocations || count.bytes > limit.bytes {
+            on_exceed(count);
+        }
+        ret
+    }
+}
+
+impl<T> OverAllocBudget for T {}
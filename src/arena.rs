@@ -0,0 +1,85 @@
+//! Allocating stage outputs into a `bumpalo` arena.
+
+use bumpalo::Bump;
+
+/// Transform by value, allocating the result in an arena (`bump.alloc(f(self))`)
+///
+/// The returned reference lives as long as the arena rather than the receiver, so arena
+/// pipelines can keep chaining without naming the lifetime at each stage.
+///
+/// ```
+/// use bumpalo::Bump;
+/// use ov::*;
+///
+/// enum Expr<'a> {
+///     Num(i64),
+///     Add(&'a Expr<'a>, &'a Expr<'a>),
+/// }
+///
+/// fn eval(e: &Expr) -> i64 {
+///     match e {
+///         Expr::Num(n) => *n,
+///         Expr::Add(a, b) => eval(a) + eval(b),
+///     }
+/// }
+///
+/// let bump = Bump::new();
+/// let one = 1.over_alloc_in(&bump, Expr::Num);
+/// let sum = (one, 2)
+///     .over_alloc_in(&bump, |(a, b)| Expr::Add(a, bump.alloc(Expr::Num(b))))
+///     .over_alloc_in(&bump, |sum| Expr::Add(sum, sum));
+/// assert_eq!(eval(sum), 6);
+/// ```
+pub trait OverAllocIn: Sized {
+    fn over_alloc_in<F, U>(self, bump: &Bump, f: F) -> &mut U
+    where
+        F: FnOnce(Self) -> U,
+    {
+        bump.alloc(f(self))
+    }
+}
+
+impl<T> OverAllocIn for T {}
+
+/// Transform each item, allocating the results as a slice in an arena
+/// (`bump.alloc_slice(self.map(f))`)
+///
+/// The iterator must know its exact length, so the slice can be allocated up front.
+///
+/// ```
+/// use bumpalo::Bump;
+/// use ov::*;
+///
+/// let bump = Bump::new();
+/// let words: &[&str] = "a bb ccc"
+///     .split(' ')
+///     .collect::<Vec<_>>()
+///     .over_alloc_slice_in(&bump, |w| &*bump.alloc_str(&w.to_uppercase()));
+/// assert_eq!(words, ["A", "BB", "CCC"]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't iterate with a known length",
+        label = "not an `ExactSizeIterator`",
+        note = "`over_alloc_slice_in` needs `IntoIterator<IntoIter: ExactSizeIterator>`; collect into a `Vec` first"
+    )
+)]
+pub trait OverAllocSliceIn: IntoIterator + Sized
+where
+    Self::IntoIter: ExactSizeIterator,
+{
+    fn over_alloc_slice_in<F, U>(self, bump: &Bump, f: F) -> &mut [U]
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        bump.alloc_slice_fill_iter(self.into_iter().map(f))
+    }
+}
+
+impl<I> OverAllocSliceIn for I
+where
+    I: IntoIterator,
+    I::IntoIter: ExactSizeIterator,
+{
+}
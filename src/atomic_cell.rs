@@ -0,0 +1,51 @@
+//! Updates of crossbeam's `AtomicCell`.
+
+use crossbeam_utils::atomic::AtomicCell;
+
+/// Update an `AtomicCell` in place, returning the stored value
+/// (`loop { compare_exchange(v, f(v)) }`)
+///
+/// The closure may run more than once if other threads update the cell at the same time,
+/// so it should be free of side effects. Use `AtomicCell::fetch_update` to get the previous
+/// value instead.
+///
+/// ```
+/// use crossbeam_utils::atomic::AtomicCell;
+/// use ov::*;
+///
+/// let high_score = AtomicCell::new(10u32);
+/// assert_eq!(high_score.over_atomic_cell(|best| best.max(25)), 25);
+/// assert_eq!(high_score.over_atomic_cell(|best| best.max(15)), 25);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_atomic_cell` is only implemented for `AtomicCell<T>`, not `{Self}`",
+        label = "not an `AtomicCell`",
+        note = "the value in the cell must be `Copy + Eq`"
+    )
+)]
+pub trait OverAtomicCell<T> {
+    fn over_atomic_cell<F>(&self, f: F) -> T
+    where
+        F: FnMut(T) -> T;
+}
+
+impl<T> OverAtomicCell<T> for AtomicCell<T>
+where
+    T: Copy + Eq,
+{
+    fn over_atomic_cell<F>(&self, mut f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        let mut prev = self.load();
+        loop {
+            let next = f(prev);
+            match self.compare_exchange(prev, next) {
+                Ok(_) => return next,
+                Err(actual) => prev = actual,
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! An audit trail of the inputs and outputs of stages.
+
+use crate::record::Entry;
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+
+/// A destination for audit entries
+///
+/// Unlike a [`RecordSink`](record/trait.RecordSink.html), a sink can refuse an entry, and
+/// the stage reports the failure instead of carrying on untraced.
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't an audit sink",
+        label = "not an `AuditSink`",
+        note = "implement `AuditSink` to receive audit entries"
+    )
+)]
+pub trait AuditSink {
+    fn audit(&self, entry: Entry) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+impl<S> AuditSink for &S
+where
+    S: AuditSink + ?Sized,
+{
+    fn audit(&self, entry: Entry) -> Result<(), Box<dyn Error + Send + Sync>> {
+        S::audit(self, entry)
+    }
+}
+
+/// The reason an audited stage couldn't be traced
+#[derive(Debug)]
+pub enum AuditError {
+    /// The input or output couldn't be serialized
+    Serialize(serde_json::Error),
+    /// The sink refused the entry
+    Sink(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditError::Serialize(e) => write!(f, "failed to serialize audit entry: {}", e),
+            AuditError::Sink(e) => write!(f, "audit sink failed: {}", e),
+        }
+    }
+}
+
+impl Error for AuditError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AuditError::Serialize(e) => Some(e),
+            AuditError::Sink(e) => Some(&**e),
+        }
+    }
+}
+
+/// Transform by value, sending the serialized input and output to an audit sink
+/// (`self |> f |> sink.audit(..)?`)
+///
+/// The closure isn't run if the input can't be serialized. If the output can't be
+/// serialized, or the sink fails, the output is discarded and the error returned.
+///
+/// ```
+/// use ov::record::Entry;
+/// use ov::*;
+/// use std::error::Error;
+/// use std::sync::Mutex;
+///
+/// #[derive(Default)]
+/// struct Trail(Mutex<Vec<String>>);
+///
+/// impl AuditSink for Trail {
+///     fn audit(&self, entry: Entry) -> Result<(), Box<dyn Error + Send + Sync>> {
+///         let line = format!("{}: {} -> {}", entry.stage, entry.input, entry.output);
+///         self.0.lock().unwrap().push(line);
+///         Ok(())
+///     }
+/// }
+///
+/// let trail = Trail::default();
+/// let sink: &dyn AuditSink = &trail;
+///
+/// let total = vec![100, 250]
+///     .over_audited(sink, "sum", |v| v.iter().sum::<u32>())
+///     .unwrap();
+/// assert_eq!(total, 350);
+/// assert_eq!(trail.0.lock().unwrap()[0], "sum: [100,250] -> 350");
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be audited because it doesn't implement `Serialize`",
+        label = "not `Serialize`",
+        note = "derive `serde::Serialize` for the input of the stage"
+    )
+)]
+pub trait OverAudited: Serialize + Sized {
+    fn over_audited<S, F, Ret>(self, sink: &S, stage: &str, f: F) -> Result<Ret, AuditError>
+    where
+        S: AuditSink + ?Sized,
+        F: FnOnce(Self) -> Ret,
+        Ret: Serialize,
+    {
+        let input = serde_json::to_value(&self).map_err(AuditError::Serialize)?;
+        let output = f(self);
+        let entry = Entry {
+            stage: stage.to_owned(),
+            input,
+            output: serde_json::to_value(&output).map_err(AuditError::Serialize)?,
+        };
+        sink.audit(entry).map_err(AuditError::Sink)?;
+        Ok(output)
+    }
+}
+
+impl<T> OverAudited for T where T: Serialize {}
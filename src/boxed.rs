@@ -0,0 +1,99 @@
+//! Stages for boxed values.
+
+use alloc::alloc::{dealloc, Layout};
+use alloc::boxed::Box;
+use core::mem;
+use core::ptr;
+
+/// Frees a box's allocation without dropping its contents, if the closure unwinds
+struct FreeOnUnwind {
+    ptr: *mut u8,
+    layout: Layout,
+}
+
+impl Drop for FreeOnUnwind {
+    fn drop(&mut self) {
+        // SAFETY: the allocation came from `Box` with this layout, and its contents were
+        // moved out before the closure ran.
+        unsafe { dealloc(self.ptr, self.layout) }
+    }
+}
+
+/// Transform the boxed value, reusing the allocation when the layouts match
+/// (`Box::new(f(*self))`)
+///
+/// When `T` and `U` have the same size and alignment, the result is written back into the
+/// same allocation. Otherwise this is the same as `Box::new(f(*self))`. If `f` panics the
+/// allocation is freed, and the moved-out value is dropped by the unwinding closure as usual.
+///
+/// ```
+/// use ov::*;
+///
+/// enum Expr {
+///     Lit(i64),
+///     Neg(Box<Expr>),
+/// }
+///
+/// fn simplify(expr: Box<Expr>) -> Box<Expr> {
+///     expr.over_map_box(|e| match e {
+///         Expr::Neg(inner) => match *simplify(inner) {
+///             Expr::Lit(n) => Expr::Lit(-n),
+///             other => Expr::Neg(Box::new(other)),
+///         },
+///         lit => lit,
+///     })
+/// }
+///
+/// let expr = Box::new(Expr::Neg(Box::new(Expr::Neg(Box::new(Expr::Lit(3))))));
+/// assert!(matches!(*simplify(expr), Expr::Lit(3)));
+///
+/// let boxed = Box::new(7u64);
+/// let addr = &*boxed as *const u64 as usize;
+/// let signed = boxed.over_map_box(|n| n as i64 - 10);
+/// assert_eq!(*signed, -3);
+/// assert_eq!(&*signed as *const i64 as usize, addr);
+///
+/// let wider = Box::new(1u8).over_map_box(|n| [n; 16]);
+/// assert_eq!(*wider, [1; 16]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_map_box` is only implemented for `Box`, not `{Self}`",
+        label = "not a `Box`",
+        note = "use `over` to transform an unboxed value"
+    )
+)]
+pub trait OverMapBox<T> {
+    fn over_map_box<F, U>(self, f: F) -> Box<U>
+    where
+        F: FnOnce(T) -> U;
+}
+
+impl<T> OverMapBox<T> for Box<T> {
+    fn over_map_box<F, U>(self, f: F) -> Box<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        let layout = Layout::new::<T>();
+        if layout != Layout::new::<U>() || layout.size() == 0 {
+            return Box::new(f(*self));
+        }
+
+        let raw = Box::into_raw(self);
+        let guard = FreeOnUnwind {
+            ptr: raw.cast(),
+            layout,
+        };
+        // SAFETY: `raw` came from `Box::into_raw`, so it's valid for reads, and the value is
+        // only read once. `U` has the same layout as `T`, so the allocation is valid for
+        // writing a `U` and can be owned by a `Box<U>`.
+        unsafe {
+            let out = f(ptr::read(raw));
+            mem::forget(guard);
+            let raw = raw.cast::<U>();
+            ptr::write(raw, out);
+            Box::from_raw(raw)
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Stages which route a value down one of two paths.
+
+use either::Either;
+
+/// Transform by one of two closures, chosen by a predicate
+/// (`if pred(&self) { Left(left(self)) } else { Right(right(self)) }`)
+///
+/// The two closures may return different types, so the chain can continue on the
+/// resulting `Either`.
+///
+/// ```
+/// use either::Either;
+/// use ov::*;
+///
+/// fn parse(s: &str) -> Either<i32, String> {
+///     s.over_branch(
+///         |s| s.starts_with('#'),
+///         |s| s[1..].parse().unwrap_or_default(),
+///         |s| s.to_uppercase(),
+///     )
+/// }
+///
+/// assert_eq!(parse("#42"), Either::Left(42));
+/// assert_eq!(parse("abc"), Either::Right("ABC".to_string()));
+/// ```
+pub trait OverBranch: Sized {
+    fn over_branch<P, FL, FR, L, R>(self, pred: P, left: FL, right: FR) -> Either<L, R>
+    where
+        P: FnOnce(&Self) -> bool,
+        FL: FnOnce(Self) -> L,
+        FR: FnOnce(Self) -> R,
+    {
+        if pred(&self) {
+            Either::Left(left(self))
+        } else {
+            Either::Right(right(self))
+        }
+    }
+}
+
+impl<T> OverBranch for T {}
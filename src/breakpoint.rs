@@ -0,0 +1,47 @@
+//! Stopping in a debugger at a stage.
+
+/// Trigger a debugger trap in debug builds, returning the receiver
+/// (`debugger trap |> self`)
+///
+/// On x86, ARM64 and RISC-V this executes the architecture's breakpoint instruction when
+/// `debug_assertions` are enabled. Elsewhere, and in release builds, it does nothing.
+/// Without a debugger attached the trap terminates the process, usually with `SIGTRAP`.
+///
+/// ```no_run
+/// use ov::*;
+///
+/// let total = vec![1, 2, 3]
+///     .into_iter()
+///     .map(|n| n * 2)
+///     .sum::<i32>()
+///     .tap_breakpoint()
+///     .over(|n| n + 1);
+/// ```
+pub trait TapBreakpoint: Sized {
+    fn tap_breakpoint(self) -> Self {
+        #[cfg(debug_assertions)]
+        trap();
+        self
+    }
+}
+
+impl<T> TapBreakpoint for T {}
+
+#[cfg(debug_assertions)]
+#[inline(always)]
+fn trap() {
+    // SAFETY: a breakpoint instruction has no effect on memory or registers; it only
+    // transfers control to the debugger, or raises SIGTRAP without one.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        core::arch::asm!("int3", options(nomem, nostack));
+    }
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        core::arch::asm!("brk #0xf000", options(nomem, nostack));
+    }
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    unsafe {
+        core::arch::asm!("ebreak", options(nomem, nostack));
+    }
+}
@@ -0,0 +1,99 @@
+//! Tripwires for stages which take longer than expected.
+
+use core::time::Duration;
+
+/// Transform by value, reporting when the closure takes longer than `limit` (`self |> f`)
+///
+/// A stage over its budget is reported to stderr along with the caller's location;
+/// [`over_time_budget_with`](#method.over_time_budget_with) takes a callback instead. The
+/// measurement only exists with the `budget` feature. Without it, both methods are `f(self)`.
+///
+/// ```
+/// use ov::*;
+/// use std::time::Duration;
+///
+/// let mut over_budget = None;
+/// let sum = vec![1, 2, 3].over_time_budget_with(
+///     Duration::from_secs(1),
+///     |v| v.iter().sum::<i32>(),
+///     |elapsed| over_budget = Some(elapsed),
+/// );
+/// assert_eq!(sum, 6);
+/// assert_eq!(over_budget, None);
+///
+/// let len = "abc".over_time_budget(Duration::from_millis(50), str::len);
+/// assert_eq!(len, 3);
+/// ```
+pub trait OverTimeBudget: Sized {
+    #[track_caller]
+    fn over_time_budget<F, Ret>(self, limit: Duration, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        #[cfg(feature = "budget")]
+        {
+            let location = core::panic::Location::caller();
+            self.over_time_budget_with(limit, f, |elapsed| {
+                eprintln!(
+                    "ov: stage at {} took {:?}, over its budget of {:?}",
+                    location, elapsed, limit
+                );
+            })
+        }
+        #[cfg(not(feature = "budget"))]
+        {
+            let _ = limit;
+            f(self)
+        }
+    }
+
+    /// Like [`over_time_budget`](#method.over_time_budget), calling `on_exceed` with the
+    /// elapsed time when the closure takes longer than `limit`
+    ///
+    /// ```
+    /// use ov::*;
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// let mut reports = Vec::new();
+    /// let doubled = 4.over_time_budget_with(
+    ///     Duration::from_millis(1),
+    ///     |n| {
+    ///         thread::sleep(Duration::from_millis(20));
+    ///         n * 2
+    ///     },
+    ///     |elapsed| reports.push(elapsed),
+    /// );
+    /// assert_eq!(doubled, 8);
+    ///
+    /// if cfg!(feature = "budget") {
+    ///     assert_eq!(reports.len(), 1);
+    ///     assert!(reports[0] >= Duration::from_millis(20));
+    /// } else {
+    ///     assert!(reports.is_empty());
+    /// }
+    /// ```
+    fn over_time_budget_with<F, E, Ret>(self, limit: Duration, f: F, on_exceed: E) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+        E: FnOnce(Duration),
+    {
+        #[cfg(feature = "budget")]
+        {
+            let start = std::time::Instant::now();
+            let ret = f(self);
+            let elapsed = start.elapsed();
+            if elapsed > limit {
+                on_exceed(elapsed);
+            }
+            ret
+        }
+        #[cfg(not(feature = "budget"))]
+        {
+            let _ = (limit, on_exceed);
+            f(self)
+        }
+    }
+}
+
+impl<T> OverTimeBudget for T {}
@@ -0,0 +1,40 @@
+//! Construction of values from a closure.
+
+/// Construct by mutating the default value (`Default::default() |> &mut`)
+///
+/// ```
+/// use ov::*;
+///
+/// #[derive(Default)]
+/// struct Config {
+///     verbose: bool,
+///     paths: Vec<String>,
+/// }
+///
+/// let config = Config::build_with(|c| {
+///     c.verbose = true;
+///     c.paths.push("/tmp".into());
+/// });
+/// assert!(config.verbose);
+/// assert_eq!(config.paths, ["/tmp"]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `Default`, so it can't be built with `build_with`",
+        label = "not `Default`",
+        note = "construct the value yourself and configure it with `over_mut`"
+    )
+)]
+pub trait BuildWith: Default {
+    fn build_with<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut value = Self::default();
+        f(&mut value);
+        value
+    }
+}
+
+impl<T> BuildWith for T where T: Default {}
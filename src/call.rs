@@ -0,0 +1,90 @@
+//! Transforming by callables which are stored elsewhere.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// Transform by value with a borrowed `Fn` (`self |> f`)
+///
+/// The callable is only borrowed, so it can live in a struct field and be used again.
+/// Unsized callables such as `dyn Fn` are accepted as well.
+///
+/// ```
+/// use ov::*;
+///
+/// struct Stage {
+///     name: &'static str,
+///     run: Box<dyn Fn(i32) -> i32>,
+/// }
+///
+/// let stages = [
+///     Stage { name: "double", run: Box::new(|n| n * 2) },
+///     Stage { name: "inc", run: Box::new(|n| n + 1) },
+/// ];
+///
+/// let n = stages.iter().fold(5, |n, stage| n.over_call(&*stage.run));
+/// assert_eq!(n, 11);
+/// assert_eq!(stages[0].name, "double");
+///
+/// fn negate(n: i32) -> i32 {
+///     -n
+/// }
+/// let f: fn(i32) -> i32 = negate;
+/// assert_eq!(3.over_call(&f), -3);
+/// ```
+pub trait OverCall: Sized {
+    fn over_call<F, Ret>(self, f: &F) -> Ret
+    where
+        F: Fn(Self) -> Ret + ?Sized,
+    {
+        f(self)
+    }
+}
+
+impl<T> OverCall for T {}
+
+/// Transform by value with a borrowed `FnMut` (`self |> f`)
+///
+/// ```
+/// use ov::*;
+///
+/// let mut seen = 0;
+/// let mut count = |n: i32| {
+///     seen += 1;
+///     n
+/// };
+///
+/// let n = 1.over_call_mut(&mut count).over_call_mut(&mut count);
+/// assert_eq!(n, 1);
+/// assert_eq!(seen, 2);
+/// ```
+pub trait OverCallMut: Sized {
+    fn over_call_mut<F, Ret>(self, f: &mut F) -> Ret
+    where
+        F: FnMut(Self) -> Ret + ?Sized,
+    {
+        f(self)
+    }
+}
+
+impl<T> OverCallMut for T {}
+
+/// Transform by value with a boxed `FnOnce` (`self |> f`)
+///
+/// ```
+/// use ov::*;
+///
+/// fn plugin(offset: i32) -> Box<dyn FnOnce(i32) -> i32> {
+///     Box::new(move |n| n + offset)
+/// }
+///
+/// assert_eq!(2.over_boxed(plugin(40)), 42);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait OverBoxed: Sized {
+    fn over_boxed<'f, Ret>(self, f: Box<dyn FnOnce(Self) -> Ret + 'f>) -> Ret {
+        f(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OverBoxed for T {}
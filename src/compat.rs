@@ -0,0 +1,3 @@
+//! Drop-in replacements for the traits of other crates, implemented with ov.
+
+pub mod tap;
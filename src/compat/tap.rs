@@ -0,0 +1,250 @@
+//! The `Tap` and `Pipe` traits of the [`tap`](https://docs.rs/tap/1) crate.
+//!
+//! The method names and signatures match `tap` 1.x, so a codebase can drop the dependency
+//! by replacing `use tap::{Pipe, Tap}` with this import. Each method delegates to the
+//! matching `ov` trait, such as [`OverRef`](../../trait.OverRef.html) for `tap`, so the two
+//! behave the same.
+//!
+//! ```
+//! use ov::compat::tap::{Pipe, Tap};
+//!
+//! let v = vec![3, 1, 2].tap_mut(|v| v.sort()).pipe(|v| v.len());
+//! assert_eq!(v, 3);
+//! ```
+
+use crate::{Over, OverDeref, OverMut, OverRef};
+use core::borrow::{Borrow, BorrowMut};
+use core::ops::{Deref, DerefMut};
+
+/// Point-free value inspection and modification, returning the receiver
+pub trait Tap
+where
+    Self: Sized,
+{
+    fn tap(self, func: impl FnOnce(&Self)) -> Self {
+        OverRef::over_ref(&self, func);
+        self
+    }
+
+    fn tap_mut(mut self, func: impl FnOnce(&mut Self)) -> Self {
+        OverMut::over_mut(&mut self, func);
+        self
+    }
+
+    fn tap_borrow<B>(self, func: impl FnOnce(&B)) -> Self
+    where
+        Self: Borrow<B>,
+        B: ?Sized,
+    {
+        Over::over(Borrow::<B>::borrow(&self), func);
+        self
+    }
+
+    fn tap_borrow_mut<B>(mut self, func: impl FnOnce(&mut B)) -> Self
+    where
+        Self: BorrowMut<B>,
+        B: ?Sized,
+    {
+        Over::over(BorrowMut::<B>::borrow_mut(&mut self), func);
+        self
+    }
+
+    fn tap_ref<R>(self, func: impl FnOnce(&R)) -> Self
+    where
+        Self: AsRef<R>,
+        R: ?Sized,
+    {
+        Over::over(AsRef::<R>::as_ref(&self), func);
+        self
+    }
+
+    fn tap_ref_mut<R>(mut self, func: impl FnOnce(&mut R)) -> Self
+    where
+        Self: AsMut<R>,
+        R: ?Sized,
+    {
+        Over::over(AsMut::<R>::as_mut(&mut self), func);
+        self
+    }
+
+    fn tap_deref<T>(self, func: impl FnOnce(&T)) -> Self
+    where
+        Self: Deref<Target = T>,
+        T: ?Sized,
+    {
+        OverDeref::over_deref(&self, func);
+        self
+    }
+
+    fn tap_deref_mut<T>(mut self, func: impl FnOnce(&mut T)) -> Self
+    where
+        Self: DerefMut + Deref<Target = T>,
+        T: ?Sized,
+    {
+        Over::over(DerefMut::deref_mut(&mut self), func);
+        self
+    }
+
+    fn tap_dbg(self, func: impl FnOnce(&Self)) -> Self {
+        if cfg!(debug_assertions) {
+            return self.tap(func);
+        }
+        self
+    }
+
+    fn tap_mut_dbg(self, func: impl FnOnce(&mut Self)) -> Self {
+        if cfg!(debug_assertions) {
+            return self.tap_mut(func);
+        }
+        self
+    }
+
+    fn tap_borrow_dbg<B>(self, func: impl FnOnce(&B)) -> Self
+    where
+        Self: Borrow<B>,
+        B: ?Sized,
+    {
+        if cfg!(debug_assertions) {
+            return self.tap_borrow(func);
+        }
+        self
+    }
+
+    fn tap_borrow_mut_dbg<B>(self, func: impl FnOnce(&mut B)) -> Self
+    where
+        Self: BorrowMut<B>,
+        B: ?Sized,
+    {
+        if cfg!(debug_assertions) {
+            return self.tap_borrow_mut(func);
+        }
+        self
+    }
+
+    fn tap_ref_dbg<R>(self, func: impl FnOnce(&R)) -> Self
+    where
+        Self: AsRef<R>,
+        R: ?Sized,
+    {
+        if cfg!(debug_assertions) {
+            return self.tap_ref(func);
+        }
+        self
+    }
+
+    fn tap_ref_mut_dbg<R>(self, func: impl FnOnce(&mut R)) -> Self
+    where
+        Self: AsMut<R>,
+        R: ?Sized,
+    {
+        if cfg!(debug_assertions) {
+            return self.tap_ref_mut(func);
+        }
+        self
+    }
+
+    fn tap_deref_dbg<T>(self, func: impl FnOnce(&T)) -> Self
+    where
+        Self: Deref<Target = T>,
+        T: ?Sized,
+    {
+        if cfg!(debug_assertions) {
+            return self.tap_deref(func);
+        }
+        self
+    }
+
+    fn tap_deref_mut_dbg<T>(self, func: impl FnOnce(&mut T)) -> Self
+    where
+        Self: DerefMut + Deref<Target = T>,
+        T: ?Sized,
+    {
+        if cfg!(debug_assertions) {
+            return self.tap_deref_mut(func);
+        }
+        self
+    }
+}
+
+impl<T> Tap for T where T: Sized {}
+
+/// Suffix-position function application
+pub trait Pipe {
+    fn pipe<R>(self, func: impl FnOnce(Self) -> R) -> R
+    where
+        Self: Sized,
+        R: Sized,
+    {
+        Over::over(self, func)
+    }
+
+    fn pipe_ref<'a, R>(&'a self, func: impl FnOnce(&'a Self) -> R) -> R
+    where
+        R: 'a + Sized,
+    {
+        Over::over(self, func)
+    }
+
+    fn pipe_ref_mut<'a, R>(&'a mut self, func: impl FnOnce(&'a mut Self) -> R) -> R
+    where
+        R: 'a + Sized,
+    {
+        Over::over(self, func)
+    }
+
+    fn pipe_borrow<'a, B, R>(&'a self, func: impl FnOnce(&'a B) -> R) -> R
+    where
+        Self: Borrow<B>,
+        B: 'a + ?Sized,
+        R: 'a + Sized,
+    {
+        Over::over(Borrow::<B>::borrow(self), func)
+    }
+
+    fn pipe_borrow_mut<'a, B, R>(&'a mut self, func: impl FnOnce(&'a mut B) -> R) -> R
+    where
+        Self: BorrowMut<B>,
+        B: 'a + ?Sized,
+        R: 'a + Sized,
+    {
+        Over::over(BorrowMut::<B>::borrow_mut(self), func)
+    }
+
+    fn pipe_as_ref<'a, U, R>(&'a self, func: impl FnOnce(&'a U) -> R) -> R
+    where
+        Self: AsRef<U>,
+        U: 'a + ?Sized,
+        R: 'a + Sized,
+    {
+        Over::over(AsRef::<U>::as_ref(self), func)
+    }
+
+    fn pipe_as_mut<'a, U, R>(&'a mut self, func: impl FnOnce(&'a mut U) -> R) -> R
+    where
+        Self: AsMut<U>,
+        U: 'a + ?Sized,
+        R: 'a + Sized,
+    {
+        Over::over(AsMut::<U>::as_mut(self), func)
+    }
+
+    fn pipe_deref<'a, T, R>(&'a self, func: impl FnOnce(&'a T) -> R) -> R
+    where
+        Self: Deref<Target = T>,
+        T: 'a + ?Sized,
+        R: 'a + Sized,
+    {
+        Over::over(Deref::deref(self), func)
+    }
+
+    fn pipe_deref_mut<'a, T, R>(&'a mut self, func: impl FnOnce(&'a mut T) -> R) -> R
+    where
+        Self: DerefMut + Deref<Target = T>,
+        T: 'a + ?Sized,
+        R: 'a + Sized,
+    {
+        Over::over(DerefMut::deref_mut(self), func)
+    }
+}
+
+impl<T> Pipe for T where T: ?Sized {}
@@ -0,0 +1,292 @@
+//! Async stages which process items concurrently, with `futures`.
+
+use core::future::Future;
+use core::pin::pin;
+use futures_util::future::{self, Either, Join};
+use futures_util::stream::{self, BufferUnordered, Buffered, ForEachConcurrent, Iter, Map};
+use futures_util::stream::{Stream, StreamExt};
+
+/// Selects the [`IntoStream`](trait.IntoStream.html) impl for streams
+pub struct FromStream;
+
+/// Selects the [`IntoStream`](trait.IntoStream.html) impl for iterables
+pub struct FromIter;
+
+/// A stream, or an iterable which can be converted into one with `stream::iter`
+///
+/// This is what the concurrent stages are implemented for. The `Marker` parameter only tells
+/// the two impls apart, and is inferred. The method isn't called `into_stream`, so that it
+/// doesn't clash with the one of `TryStreamExt`.
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is neither a stream nor iterable",
+        label = "not a `Stream` or `IntoIterator`",
+        note = "the concurrent stages are implemented for `futures::Stream` and `IntoIterator`"
+    )
+)]
+pub trait IntoStream<Marker>: Sized {
+    type Stream: Stream;
+
+    fn into_item_stream(self) -> Self::Stream;
+}
+
+impl<S> IntoStream<FromStream> for S
+where
+    S: Stream,
+{
+    type Stream = S;
+
+    fn into_item_stream(self) -> S {
+        self
+    }
+}
+
+impl<I> IntoStream<FromIter> for I
+where
+    I: IntoIterator,
+{
+    type Stream = Iter<I::IntoIter>;
+
+    fn into_item_stream(self) -> Iter<I::IntoIter> {
+        stream::iter(self)
+    }
+}
+
+/// Run an async closure on each item, with at most `limit` running at once
+/// (`self.for_each_concurrent(limit, f)`)
+///
+/// The returned future completes once every item has been processed. A `limit` of `None`
+/// runs them all at once.
+///
+/// ```
+/// use futures_util::FutureExt;
+/// use ov::*;
+/// use std::sync::Mutex;
+///
+/// let fetched = Mutex::new(Vec::new());
+/// let sink = &fetched;
+///
+/// vec!["a", "b", "c"]
+///     .over_for_each_concurrent(2, |url| async move {
+///         sink.lock().unwrap().push(url.to_uppercase());
+///     })
+///     .now_or_never()
+///     .unwrap();
+///
+/// let mut fetched = fetched.into_inner().unwrap();
+/// fetched.sort();
+/// assert_eq!(fetched, ["A", "B", "C"]);
+///
+/// let total = Mutex::new(0);
+/// futures_util::stream::iter(1..=4)
+///     .over_for_each_concurrent(None, |n| {
+///         *total.lock().unwrap() += n;
+///         async {}
+///     })
+///     .now_or_never()
+///     .unwrap();
+/// assert_eq!(total.into_inner().unwrap(), 10);
+/// ```
+pub trait OverForEachConcurrent<Marker>: IntoStream<Marker> {
+    fn over_for_each_concurrent<F, Fut>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> ForEachConcurrent<Self::Stream, Fut, F>
+    where
+        F: FnMut(<Self::Stream as Stream>::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        self.into_item_stream().for_each_concurrent(limit, f)
+    }
+}
+
+impl<T, Marker> OverForEachConcurrent<Marker> for T where T: IntoStream<Marker> {}
+
+/// Map each item to a future, running at most `limit` at once and yielding their outputs in
+/// the order of the items (`self.map(f).buffered(limit)`)
+///
+/// ```
+/// use futures_util::{FutureExt, StreamExt};
+/// use ov::*;
+///
+/// let lengths: Vec<usize> = vec!["a", "bb", "ccc"]
+///     .over_buffered(2, |s| async move { s.len() })
+///     .collect()
+///     .now_or_never()
+///     .unwrap();
+/// assert_eq!(lengths, [1, 2, 3]);
+/// ```
+pub trait OverBuffered<Marker>: IntoStream<Marker> {
+    fn over_buffered<F, Fut>(self, limit: usize, f: F) -> Buffered<Map<Self::Stream, F>>
+    where
+        F: FnMut(<Self::Stream as Stream>::Item) -> Fut,
+        Fut: Future,
+    {
+        self.into_item_stream().map(f).buffered(limit)
+    }
+}
+
+impl<T, Marker> OverBuffered<Marker> for T where T: IntoStream<Marker> {}
+
+/// Map each item to a future, running at most `limit` at once and yielding their outputs as
+/// they complete (`self.map(f).buffer_unordered(limit)`)
+///
+/// ```
+/// use futures_util::{FutureExt, StreamExt};
+/// use ov::*;
+///
+/// let mut squares: Vec<u32> = futures_util::stream::iter(1..=4)
+///     .over_buffer_unordered(4, |n| async move { n * n })
+///     .collect()
+///     .now_or_never()
+///     .unwrap();
+/// squares.sort();
+/// assert_eq!(squares, [1, 4, 9, 16]);
+/// ```
+pub trait OverBufferUnordered<Marker>: IntoStream<Marker> {
+    fn over_buffer_unordered<F, Fut>(
+        self,
+        limit: usize,
+        f: F,
+    ) -> BufferUnordered<Map<Self::Stream, F>>
+    where
+        F: FnMut(<Self::Stream as Stream>::Item) -> Fut,
+        Fut: Future,
+    {
+        self.into_item_stream().map(f).buffer_unordered(limit)
+    }
+}
+
+impl<T, Marker> OverBufferUnordered<Marker> for T where T: IntoStream<Marker> {}
+
+/// Run two async closures on the value, resolving to whichever finishes first
+/// (`select(f(self.clone()), g(self))`)
+///
+/// The first closure receives a clone. The slower future is dropped once the other
+/// completes, and if both are ready on the same poll the first one wins. Racing a request
+/// against a timer gives a timeout with a fallback.
+///
+/// ```
+/// use futures_util::future::{pending, Either};
+/// use futures_util::FutureExt;
+/// use ov::*;
+///
+/// let winner = String::from("primary")
+///     .over_race(|s| async move { s.len() }, |_| pending::<()>())
+///     .now_or_never();
+/// assert!(matches!(winner, Some(Either::Left(7))));
+///
+/// let fallback = 5
+///     .over_race(|_| pending::<i32>(), |n| async move { n * 2 })
+///     .now_or_never();
+/// assert!(matches!(fallback, Some(Either::Right(10))));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be raced because it doesn't implement `Clone`",
+        label = "not `Clone`",
+        note = "the first closure receives a clone of the value"
+    )
+)]
+pub trait OverRace: Clone {
+    fn over_race<F, G, FA, FB>(
+        self,
+        f: F,
+        g: G,
+    ) -> impl Future<Output = Either<FA::Output, FB::Output>>
+    where
+        F: FnOnce(Self) -> FA,
+        G: FnOnce(Self) -> FB,
+        FA: Future,
+        FB: Future,
+    {
+        let a = f(self.clone());
+        let b = g(self);
+        async move {
+            match future::select(pin!(a), pin!(b)).await {
+                Either::Left((a, _)) => Either::Left(a),
+                Either::Right((b, _)) => Either::Right(b),
+            }
+        }
+    }
+}
+
+impl<T> OverRace for T where T: Clone {}
+
+/// Run two async closures on the value, resolving to both results once both finish
+/// (`join(f(self.clone()), g(self))`)
+///
+/// The first closure receives a clone, and the futures make progress concurrently. See
+/// [`over_join_ref`](trait.OverJoinRef.html) to pass a shared reference to both instead.
+///
+/// ```
+/// use futures_util::FutureExt;
+/// use ov::*;
+///
+/// let (len, upper) = String::from("abc")
+///     .over_join(|s| async move { s.len() }, |s| async move { s.to_uppercase() })
+///     .now_or_never()
+///     .unwrap();
+/// assert_eq!((len, upper.as_str()), (3, "ABC"));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be joined because it doesn't implement `Clone`",
+        label = "not `Clone`",
+        note = "use `over_join_ref` to pass a shared reference to both closures"
+    )
+)]
+pub trait OverJoin: Clone {
+    fn over_join<F, G, FA, FB>(self, f: F, g: G) -> Join<FA, FB>
+    where
+        F: FnOnce(Self) -> FA,
+        G: FnOnce(Self) -> FB,
+        FA: Future,
+        FB: Future,
+    {
+        future::join(f(self.clone()), g(self))
+    }
+}
+
+impl<T> OverJoin for T where T: Clone {}
+
+/// Run two async closures on a shared reference, resolving to both results once both
+/// finish (`join(f(&self), g(&self))`)
+///
+/// ```
+/// use futures_util::FutureExt;
+/// use ov::*;
+///
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// async fn posts(id: u32) -> Vec<&'static str> {
+///     if id == 1 { vec!["hello"] } else { vec![] }
+/// }
+///
+/// let user = User { id: 1, name: "ann".to_owned() };
+/// let (posts, greeting) = user
+///     .over_join_ref(|u| posts(u.id), |u| async move { format!("hi {}", u.name) })
+///     .now_or_never()
+///     .unwrap();
+/// assert_eq!((posts, greeting.as_str()), (vec!["hello"], "hi ann"));
+/// ```
+pub trait OverJoinRef {
+    fn over_join_ref<'a, F, G, FA, FB>(&'a self, f: F, g: G) -> Join<FA, FB>
+    where
+        F: FnOnce(&'a Self) -> FA,
+        G: FnOnce(&'a Self) -> FB,
+        FA: Future,
+        FB: Future,
+    {
+        future::join(f(self), g(self))
+    }
+}
+
+impl<T> OverJoinRef for T where T: ?Sized {}
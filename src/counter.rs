@@ -0,0 +1,152 @@
+//! Call counting for pipeline stages.
+
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "sync")]
+use std::collections::BTreeMap;
+#[cfg(feature = "sync")]
+use std::sync::{Mutex, PoisonError};
+
+/// A counter which can be incremented through a shared reference
+///
+/// Implemented for [`Counter`](struct.Counter.html), `AtomicU64`, `AtomicUsize`, and, with
+/// `alloc`, an `Arc` of any of these.
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a counter",
+        label = "not an `Increment`",
+        note = "counters are `Counter`, `AtomicU64`, `AtomicUsize`, or an `Arc` of these"
+    )
+)]
+pub trait Increment {
+    fn increment(&self);
+}
+
+impl Increment for AtomicU64 {
+    fn increment(&self) {
+        self.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl Increment for AtomicUsize {
+    fn increment(&self) {
+        self.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C> Increment for Arc<C>
+where
+    C: Increment + ?Sized,
+{
+    fn increment(&self) {
+        C::increment(self);
+    }
+}
+
+/// A thread-safe count of how many times a stage ran
+///
+/// ```
+/// use ov::*;
+///
+/// static PARSED: Counter = Counter::new();
+///
+/// let total: i32 = ["1", "2", "3"]
+///     .iter()
+///     .map(|s| s.parse::<i32>().unwrap().over_counted(&PARSED))
+///     .sum();
+///
+/// assert_eq!(total, 6);
+/// assert_eq!(PARSED.get(), 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: AtomicU64,
+}
+
+impl Counter {
+    pub const fn new() -> Self {
+        Counter {
+            count: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Set the count back to zero, returning the previous count
+    pub fn reset(&self) -> u64 {
+        self.count.swap(0, Ordering::Relaxed)
+    }
+}
+
+impl Increment for Counter {
+    fn increment(&self) {
+        self.count.increment();
+    }
+}
+
+/// Increment a counter, then pass the value through (`self |> counter.increment()`)
+pub trait OverCounted: Sized {
+    fn over_counted<C>(self, counter: &C) -> Self
+    where
+        C: Increment + ?Sized,
+    {
+        counter.increment();
+        self
+    }
+}
+
+impl<T> OverCounted for T {}
+
+/// A registry of named counters
+///
+/// ```
+/// use ov::*;
+///
+/// let counters = Counters::new();
+/// let hits = counters.counter("cache.hit");
+///
+/// 1.over_counted(&hits).over_counted(&counters.counter("cache.hit"));
+/// "x".over_counted(&counters.counter("cache.miss"));
+///
+/// assert_eq!(
+///     counters.snapshot(),
+///     [("cache.hit".to_owned(), 2), ("cache.miss".to_owned(), 1)]
+/// );
+/// ```
+#[cfg(feature = "sync")]
+#[derive(Debug, Default)]
+pub struct Counters {
+    counters: Mutex<BTreeMap<String, Arc<Counter>>>,
+}
+
+#[cfg(feature = "sync")]
+impl Counters {
+    pub fn new() -> Self {
+        Counters::default()
+    }
+
+    /// The counter with this name, created at zero if it doesn't exist yet
+    pub fn counter(&self, name: &str) -> Arc<Counter> {
+        let mut counters = self.counters.lock().unwrap_or_else(PoisonError::into_inner);
+        match counters.get(name) {
+            Some(counter) => Arc::clone(counter),
+            None => Arc::clone(counters.entry(name.to_owned()).or_default()),
+        }
+    }
+
+    /// The name and count of every counter, sorted by name
+    pub fn snapshot(&self) -> Vec<(String, u64)> {
+        self.counters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, counter)| (name.clone(), counter.get()))
+            .collect()
+    }
+}
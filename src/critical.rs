@@ -0,0 +1,33 @@
+//! Stages which run inside a critical section, for state shared with interrupts.
+
+use critical_section::CriticalSection;
+
+/// Transform by shared reference inside a critical section (`critical_section::with`)
+///
+/// The closure also receives the `CriticalSection` token, which unlocks a
+/// `critical_section::Mutex`.
+///
+/// ```
+/// use core::cell::Cell;
+/// use critical_section::Mutex;
+/// use ov::*;
+///
+/// static TICKS: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+///
+/// let ticks = TICKS.over_critical(|ticks, cs| {
+///     let ticks = ticks.borrow(cs);
+///     ticks.set(ticks.get() + 1);
+///     ticks.get()
+/// });
+/// assert_eq!(ticks, 1);
+/// ```
+pub trait OverCritical {
+    fn over_critical<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(&Self, CriticalSection<'_>) -> Ret,
+    {
+        critical_section::with(|cs| f(self, cs))
+    }
+}
+
+impl<T> OverCritical for T where T: ?Sized {}
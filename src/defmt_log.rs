@@ -0,0 +1,66 @@
+//! Logging of intermediate values with `defmt`, for `no_std` firmware.
+
+use defmt::Format;
+
+/// The `defmt` macro used by [`TapDefmt`](trait.TapDefmt.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DefmtLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// Log the value with `defmt` at the given level, then pass it through
+///
+/// The messages are logged from this crate, so `DEFMT_LOG` filters them by the `ov` module
+/// path rather than the caller's. `DEFMT_LOG=my_firmware=debug` doesn't enable them; use
+/// `DEFMT_LOG=my_firmware=debug,ov=debug`, or a global level such as `DEFMT_LOG=debug`.
+///
+/// The example isn't run, since it needs a `defmt` transport such as `defmt-rtt`. It defines
+/// a logger which discards everything, so that it links on the host as well.
+///
+/// ```no_run
+/// use ov::*;
+///
+/// #[defmt::global_logger]
+/// struct Discard;
+///
+/// unsafe impl defmt::Logger for Discard {
+///     fn acquire() {}
+///     unsafe fn flush() {}
+///     unsafe fn release() {}
+///     unsafe fn write(_bytes: &[u8]) {}
+/// }
+///
+/// fn to_millivolts(raw: u16) -> u32 {
+///     u32::from(raw) * 3300 / 4095
+/// }
+///
+/// let raw: u16 = 2048;
+/// let millivolts = raw.tap_defmt(DefmtLevel::Debug, "raw").over(to_millivolts);
+/// assert_eq!(millivolts.tap_defmt(DefmtLevel::Info, "mV"), 1650);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be logged because it doesn't implement `defmt::Format`",
+        label = "not `Format`",
+        note = "derive `defmt::Format` for the logged value"
+    )
+)]
+pub trait TapDefmt: Format + Sized {
+    fn tap_defmt(self, level: DefmtLevel, label: &str) -> Self {
+        match level {
+            DefmtLevel::Trace => defmt::trace!("{=str}: {}", label, self),
+            DefmtLevel::Debug => defmt::debug!("{=str}: {}", label, self),
+            DefmtLevel::Info => defmt::info!("{=str}: {}", label, self),
+            DefmtLevel::Warn => defmt::warn!("{=str}: {}", label, self),
+            DefmtLevel::Error => defmt::error!("{=str}: {}", label, self),
+        }
+        self
+    }
+}
+
+impl<T> TapDefmt for T where T: Format {}
@@ -0,0 +1,108 @@
+//! Reporting what a mutation changed.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display};
+
+/// The pretty `Debug` output of a value before and after a mutation
+///
+/// `Display` shows a line-based diff, with removed lines prefixed by `-` and added lines
+/// prefixed by `+`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugDiff {
+    before: String,
+    after: String,
+}
+
+impl DebugDiff {
+    pub fn before(&self) -> &str {
+        &self.before
+    }
+
+    pub fn after(&self) -> &str {
+        &self.after
+    }
+
+    pub fn is_unchanged(&self) -> bool {
+        self.before == self.after
+    }
+}
+
+impl Display for DebugDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let before: Vec<&str> = self.before.lines().collect();
+        let after: Vec<&str> = self.after.lines().collect();
+
+        // Longest common subsequence of lines, filled from the end so the diff can be
+        // written front to back.
+        let mut lcs = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+        for i in (0..before.len()).rev() {
+            for j in (0..after.len()).rev() {
+                lcs[i][j] = if before[i] == after[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < before.len() || j < after.len() {
+            if i < before.len() && j < after.len() && before[i] == after[j] {
+                writeln!(f, "  {}", before[i])?;
+                i += 1;
+                j += 1;
+            } else if i < before.len() && (j == after.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+                writeln!(f, "- {}", before[i])?;
+                i += 1;
+            } else {
+                writeln!(f, "+ {}", after[j])?;
+                j += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Transform by unique reference, reporting how the `Debug` output changed
+/// (`&mut self |> (f(self), diff)`)
+///
+/// ```
+/// use ov::*;
+///
+/// #[derive(Debug)]
+/// struct Config {
+///     port: u16,
+///     host: &'static str,
+/// }
+///
+/// let mut config = Config { port: 80, host: "localhost" };
+/// let ((), diff) = config.over_mut_diff(|c| c.port = 8080);
+///
+/// let diff = diff.to_string();
+/// assert!(diff.contains("-     port: 80,\n+     port: 8080,\n"));
+/// assert!(diff.contains("      host: \"localhost\",\n"));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be diffed because it doesn't implement `Debug`",
+        label = "not `Debug`",
+        note = "add `#[derive(Debug)]` to the type"
+    )
+)]
+pub trait OverMutDiff: Debug {
+    fn over_mut_diff<F, Ret>(&mut self, f: F) -> (Ret, DebugDiff)
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        let before = format!("{:#?}", self);
+        let ret = f(self);
+        let after = format!("{:#?}", self);
+        (ret, DebugDiff { before, after })
+    }
+}
+
+impl<T> OverMutDiff for T where T: Debug + ?Sized {}
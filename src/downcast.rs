@@ -0,0 +1,103 @@
+//! Stages for type-erased `dyn Any` values.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// Transform by the concrete value if it's a `T`, returning the receiver otherwise
+/// (`self.downcast::<T>() |> f`)
+///
+/// Implemented for `&dyn Any` and `&mut dyn Any`, whose closures receive `&T` and `&mut T`,
+/// and `Box<dyn Any>` and `Box<dyn Any + Send>`, whose closures receive `Box<T>`. On a type
+/// mismatch the receiver is returned unchanged as the error, so it can be tried
+/// as another type. The type can be given by annotating the closure's argument or with a
+/// turbofish.
+///
+/// ```
+/// use ov::*;
+/// use std::any::Any;
+///
+/// # #[cfg(feature = "alloc")] {
+/// fn describe(msg: Box<dyn Any + Send>) -> String {
+///     msg.over_downcast(|n: Box<i32>| format!("int {}", n))
+///         .or_else(|msg| msg.over_downcast(|s: Box<String>| format!("string {:?}", s)))
+///         .unwrap_or_else(|_| "unknown".to_owned())
+/// }
+///
+/// assert_eq!(describe(Box::new(3)), "int 3");
+/// assert_eq!(describe(Box::new("hi".to_string())), "string \"hi\"");
+/// assert_eq!(describe(Box::new(1.5)), "unknown");
+///
+/// let mut counter: Box<dyn Any> = Box::new(0u32);
+/// counter.as_mut().over_downcast(|n: &mut u32| *n += 1).unwrap();
+/// assert_eq!(counter.as_ref().over_downcast::<u32, _>(|n| *n).ok(), Some(1));
+/// assert!(counter.as_ref().over_downcast::<u64, _>(|n| *n).is_err());
+/// # }
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be downcast",
+        label = "not a `dyn Any` reference or box",
+        note = "`over_downcast` is implemented for `&dyn Any`, `&mut dyn Any`, `Box<dyn Any>`, and `Box<dyn Any + Send>`"
+    )
+)]
+pub trait OverDowncast: Sized {
+    /// How the concrete value is passed to the closure
+    type Typed<T: Any>;
+
+    fn over_downcast<T, Ret>(self, f: impl FnOnce(Self::Typed<T>) -> Ret) -> Result<Ret, Self>
+    where
+        T: Any;
+}
+
+impl<'a> OverDowncast for &'a dyn Any {
+    type Typed<T: Any> = &'a T;
+
+    fn over_downcast<T, Ret>(self, f: impl FnOnce(&'a T) -> Ret) -> Result<Ret, Self>
+    where
+        T: Any,
+    {
+        self.downcast_ref().map(f).ok_or(self)
+    }
+}
+
+impl<'a> OverDowncast for &'a mut dyn Any {
+    type Typed<T: Any> = &'a mut T;
+
+    fn over_downcast<T, Ret>(self, f: impl FnOnce(&'a mut T) -> Ret) -> Result<Ret, Self>
+    where
+        T: Any,
+    {
+        // Checked first so the failed downcast doesn't hold the borrow on `self`.
+        if self.is::<T>() {
+            Ok(f(self.downcast_mut().expect("the type was just checked")))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OverDowncast for Box<dyn Any> {
+    type Typed<T: Any> = Box<T>;
+
+    fn over_downcast<T, Ret>(self, f: impl FnOnce(Box<T>) -> Ret) -> Result<Ret, Self>
+    where
+        T: Any,
+    {
+        self.downcast().map(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OverDowncast for Box<dyn Any + Send> {
+    type Typed<T: Any> = Box<T>;
+
+    fn over_downcast<T, Ret>(self, f: impl FnOnce(Box<T>) -> Ret) -> Result<Ret, Self>
+    where
+        T: Any,
+    {
+        self.downcast().map(f)
+    }
+}
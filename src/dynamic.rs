@@ -0,0 +1,69 @@
+//! Object-safe counterparts of the traits, for use as `dyn` values.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// Transform by unique reference as `dyn Any` (`&mut self as &mut dyn Any`)
+///
+/// Unlike [`OverMut`](trait.OverMut.html) this is object safe, so values of different types
+/// can be stored together as `Box<dyn OverDyn>`. Use [`over_as`](#method.over_as) to get
+/// the concrete type back.
+///
+/// A `Box<dyn OverDyn>` is itself `Any`, so it gets the blanket impl too. With `alloc`, that
+/// impl forwards to the boxed value, so the callback receives the contents rather than the
+/// box.
+///
+/// ```
+/// use ov::*;
+///
+/// let mut values: Vec<Box<dyn OverDyn>> = vec![Box::new(1i32), Box::new(String::from("a"))];
+///
+/// for value in &mut values {
+///     value.over_as(|n: &mut i32| *n += 1);
+///     value.over_as(|s: &mut String| s.push('b'));
+/// }
+///
+/// assert_eq!(values[0].over_as(|n: &mut i32| *n), Some(2));
+/// assert_eq!(values[1].over_as(|s: &mut String| s.clone()), Some("ab".to_owned()));
+///
+/// # #[cfg(feature = "alloc")] {
+/// let mut count = 0;
+/// values[0].over_dyn(&mut |any| count += any.is::<i32>() as i32);
+/// assert_eq!(count, 1);
+/// # }
+/// ```
+pub trait OverDyn: Any {
+    fn over_dyn(&mut self, f: &mut dyn FnMut(&mut dyn Any));
+}
+
+impl<T> OverDyn for T
+where
+    T: Any,
+{
+    fn over_dyn(&mut self, f: &mut dyn FnMut(&mut dyn Any)) {
+        let any: &mut dyn Any = self;
+        #[cfg(feature = "alloc")]
+        if let Some(inner) = any.downcast_mut::<Box<dyn OverDyn>>() {
+            return inner.as_mut().over_dyn(f);
+        }
+        f(any)
+    }
+}
+
+impl dyn OverDyn {
+    /// Transform by unique reference if the value is a `T`, returning `None` otherwise
+    pub fn over_as<T, Ret>(&mut self, f: impl FnOnce(&mut T) -> Ret) -> Option<Ret>
+    where
+        T: Any,
+    {
+        let mut f = Some(f);
+        let mut ret = None;
+        self.over_dyn(&mut |any| {
+            if let Some(value) = any.downcast_mut::<T>() {
+                ret = f.take().map(|f| f(value));
+            }
+        });
+        ret
+    }
+}
@@ -0,0 +1,79 @@
+//! Pipelines which pass a shared context to every stage.
+
+/// A value together with a context reference which is passed to every stage
+///
+/// Created with [`over_env`](trait.OverEnv.html). Each call to [`over`](#method.over)
+/// passes the value and the context to the closure, so the closures don't each need to
+/// capture it.
+///
+/// ```
+/// use ov::*;
+///
+/// struct Config {
+///     prefix: &'static str,
+///     width: usize,
+/// }
+///
+/// let config = Config { prefix: "> ", width: 6 };
+///
+/// let line = "hi"
+///     .over_env(&config)
+///     .over(|s, c| format!("{}{}", c.prefix, s))
+///     .over(|s, c| format!("{:>1$}", s, c.width))
+///     .into_value();
+///
+/// assert_eq!(line, "  > hi");
+/// ```
+#[derive(Debug)]
+pub struct WithEnv<'e, E: ?Sized, T> {
+    value: T,
+    env: &'e E,
+}
+
+impl<'e, E: ?Sized, T> WithEnv<'e, E, T> {
+    pub fn new(value: T, env: &'e E) -> Self {
+        WithEnv { value, env }
+    }
+
+    /// Run a stage, passing it the value and the context
+    pub fn over<F, U>(self, f: F) -> WithEnv<'e, E, U>
+    where
+        F: FnOnce(T, &'e E) -> U,
+    {
+        WithEnv {
+            value: f(self.value, self.env),
+            env: self.env,
+        }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn env(&self) -> &'e E {
+        self.env
+    }
+
+    /// Finish the pipeline, discarding the context
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<'e, E: ?Sized, T: Clone> Clone for WithEnv<'e, E, T> {
+    fn clone(&self) -> Self {
+        WithEnv {
+            value: self.value.clone(),
+            env: self.env,
+        }
+    }
+}
+
+/// Start a pipeline with a context (`self |> WithEnv::new(self, env)`)
+pub trait OverEnv: Sized {
+    fn over_env<E: ?Sized>(self, env: &E) -> WithEnv<'_, E, Self> {
+        WithEnv::new(self, env)
+    }
+}
+
+impl<T> OverEnv for T {}
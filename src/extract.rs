@@ -0,0 +1,156 @@
+//! Taking the matching elements out of a collection.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
+
+/// Take out the elements matching `pred`, pass them to `f`, and return the rest
+/// (`self |> { f(self.extract_if(pred).collect()); self }`)
+///
+/// The taken elements keep their order, as do the remaining ones. The predicate sees map
+/// entries as `(&key, &value)`, and `f` receives them as `(key, value)` pairs.
+///
+/// The returned `Vec` reuses the receiver's allocation: the remaining elements are collected
+/// back from its `into_iter()`, which std does in place. With `std`, a `HashMap` is drained
+/// and refilled with the remaining entries, so it keeps its capacity and hasher. A `BTreeMap`
+/// has no such storage to keep, and is rebuilt from the remaining entries in sorted order,
+/// in linear time. None of these need `extract_if`, which is newer than the oldest compilers
+/// this crate supports.
+///
+/// ```
+/// use ov::*;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let mut done = Vec::new();
+/// let mut tasks = Vec::with_capacity(8);
+/// tasks.extend([("lint", true), ("test", false)]);
+/// let pending = tasks.over_extract_if(|task| task.1, |finished| done.extend(finished));
+/// assert_eq!(pending, [("test", false)]);
+/// assert_eq!(pending.capacity(), 8);
+/// assert_eq!(done, [("lint", true)]);
+///
+/// let mut expired = Vec::new();
+/// let sessions = BTreeMap::from([("ann", 30), ("bob", 0), ("cy", 0)])
+///     .over_extract_if(|(_, ttl)| *ttl == 0, |gone| expired = gone);
+/// assert_eq!(sessions, BTreeMap::from([("ann", 30)]));
+/// assert_eq!(expired, [("bob", 0), ("cy", 0)]);
+///
+/// let mut cache = HashMap::with_capacity(64);
+/// cache.extend([(1, "one"), (2, "two")]);
+/// let cache = cache.over_extract_if(|(key, _)| *key == 1, drop);
+/// assert_eq!(cache, HashMap::from([(2, "two")]));
+/// assert!(cache.capacity() >= 64);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a collection which elements can be extracted from",
+        label = "not a `Vec`, `BTreeMap` or `HashMap`",
+        note = "`over_extract_if` is implemented for `Vec<T>`, `BTreeMap<K, V>` and `HashMap<K, V, S>`"
+    )
+)]
+pub trait OverExtractIf {
+    /// An element, as passed to `f`
+    type Item;
+    /// A borrowed element, as passed to `pred`
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    fn over_extract_if<P, F>(self, pred: P, f: F) -> Self
+    where
+        P: FnMut(Self::Borrowed<'_>) -> bool,
+        F: FnOnce(Vec<Self::Item>);
+}
+
+impl<T> OverExtractIf for Vec<T> {
+    type Item = T;
+    type Borrowed<'a>
+        = &'a T
+    where
+        T: 'a;
+
+    fn over_extract_if<P, F>(self, mut pred: P, f: F) -> Self
+    where
+        P: FnMut(&T) -> bool,
+        F: FnOnce(Vec<T>),
+    {
+        let mut taken = Vec::new();
+        let rest = self
+            .into_iter()
+            .filter_map(|item| {
+                if pred(&item) {
+                    taken.push(item);
+                    None
+                } else {
+                    Some(item)
+                }
+            })
+            .collect();
+        f(taken);
+        rest
+    }
+}
+
+impl<K: Ord, V> OverExtractIf for BTreeMap<K, V> {
+    type Item = (K, V);
+    type Borrowed<'a>
+        = (&'a K, &'a V)
+    where
+        K: 'a,
+        V: 'a;
+
+    fn over_extract_if<P, F>(self, mut pred: P, f: F) -> Self
+    where
+        P: for<'a> FnMut((&'a K, &'a V)) -> bool,
+        F: FnOnce(Vec<(K, V)>),
+    {
+        let mut taken = Vec::new();
+        let mut rest = Vec::with_capacity(self.len());
+        for (k, v) in self {
+            if pred((&k, &v)) {
+                taken.push((k, v));
+            } else {
+                rest.push((k, v));
+            }
+        }
+        f(taken);
+        rest.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> OverExtractIf for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = (K, V);
+    type Borrowed<'a>
+        = (&'a K, &'a V)
+    where
+        K: 'a,
+        V: 'a,
+        S: 'a;
+
+    fn over_extract_if<P, F>(mut self, mut pred: P, f: F) -> Self
+    where
+        P: for<'a> FnMut((&'a K, &'a V)) -> bool,
+        F: FnOnce(Vec<(K, V)>),
+    {
+        let entries: Vec<(K, V)> = self.drain().collect();
+        let mut taken = Vec::new();
+        for (k, v) in entries {
+            if pred((&k, &v)) {
+                taken.push((k, v));
+            } else {
+                self.insert(k, v);
+            }
+        }
+        f(taken);
+        self
+    }
+}
@@ -0,0 +1,163 @@
+//! Finishers end a chain by moving the value into a common wrapper.
+
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use core::cell::RefCell;
+use core::pin::Pin;
+#[cfg(feature = "sync")]
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Finish by pinning on the heap (`Box::pin(self)`)
+///
+/// The result coerces to a pinned trait object, so a future can be boxed inline.
+///
+/// ```
+/// use ov::*;
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// fn make_fut() -> impl Future<Output = i32> {
+///     async { 5 }
+/// }
+///
+/// let fut: Pin<Box<dyn Future<Output = i32>>> = make_fut().pin_boxed();
+/// ```
+pub trait PinBoxed: Sized {
+    fn pin_boxed(self) -> Pin<Box<Self>> {
+        Box::pin(self)
+    }
+}
+
+impl<T> PinBoxed for T {}
+
+/// Finish by leaking a heap allocation (`Box::leak(Box::new(self))`)
+///
+/// Useful for values which are built once at startup and live until the program exits.
+///
+/// ```
+/// use ov::*;
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let config: &'static mut Config = Config { name: "app".into() }.over_leak();
+/// config.name.push_str("-1");
+/// assert_eq!(config.name, "app-1");
+/// ```
+pub trait OverLeak: Sized + 'static {
+    fn over_leak(self) -> &'static mut Self {
+        Box::leak(Box::new(self))
+    }
+}
+
+impl<T> OverLeak for T where T: 'static {}
+
+/// Finish by leaking a heap allocation as a shared reference (`&*Box::leak(Box::new(self))`)
+///
+/// ```
+/// use ov::*;
+///
+/// let names: &'static Vec<&str> = vec!["a", "b"].over_leak_ref();
+/// std::thread::spawn(move || assert_eq!(names.len(), 2)).join().unwrap();
+/// ```
+pub trait OverLeakRef: Sized + 'static {
+    fn over_leak_ref(self) -> &'static Self {
+        Box::leak(Box::new(self))
+    }
+}
+
+impl<T> OverLeakRef for T where T: 'static {}
+
+/// Finish by sharing behind a mutex (`Arc::new(Mutex::new(self))`)
+///
+/// Values which never leave their thread can use the cheaper [`shared`](trait.Shared.html).
+///
+/// ```
+/// use ov::*;
+///
+/// let counts = vec![0; 4].synchronized();
+/// let worker = counts.clone();
+/// std::thread::spawn(move || worker.lock().unwrap()[1] += 1).join().unwrap();
+/// assert_eq!(*counts.lock().unwrap(), [0, 1, 0, 0]);
+/// ```
+#[cfg(feature = "sync")]
+pub trait Synchronized: Sized {
+    fn synchronized(self) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(self))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T> Synchronized for T {}
+
+/// Finish by sharing behind a read-write lock (`Arc::new(RwLock::new(self))`)
+///
+/// ```
+/// use ov::*;
+///
+/// let config = String::from("debug").rw_locked();
+/// assert_eq!(*config.read().unwrap(), "debug");
+/// config.write().unwrap().push_str("=1");
+/// assert_eq!(*config.read().unwrap(), "debug=1");
+/// ```
+#[cfg(feature = "sync")]
+pub trait RwLocked: Sized {
+    fn rw_locked(self) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(self))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T> RwLocked for T {}
+
+/// Finish by sharing within one thread (`Rc::new(RefCell::new(self))`)
+///
+/// This is the single-threaded counterpart of [`synchronized`](trait.Synchronized.html):
+/// the borrow checks are cheaper than locking, but the result can't be sent to other
+/// threads. Prefer `synchronized` as soon as the value crosses a thread boundary.
+///
+/// ```
+/// use ov::*;
+///
+/// let log = Vec::new().shared();
+/// let handler = {
+///     let log = log.clone();
+///     move |event: &str| log.borrow_mut().push(event.to_owned())
+/// };
+/// handler("click");
+/// assert_eq!(*log.borrow(), ["click"]);
+/// ```
+pub trait Shared: Sized {
+    fn shared(self) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(self))
+    }
+}
+
+impl<T> Shared for T {}
+
+/// Finish by sharing within one thread, along with a weak reference
+/// (`Rc::new(RefCell::new(self))`, `Rc::downgrade`)
+///
+/// A weak reference alone would drop the value immediately, so the strong reference is
+/// returned with it. Give the weak one to parents, observers and other back references,
+/// so that cycles don't keep the value alive.
+///
+/// ```
+/// use ov::*;
+///
+/// let (node, parent_link) = String::from("child").weak_shared();
+/// assert_eq!(*parent_link.upgrade().unwrap().borrow(), "child");
+///
+/// drop(node);
+/// assert!(parent_link.upgrade().is_none());
+/// ```
+pub trait WeakShared: Sized {
+    fn weak_shared(self) -> (Rc<RefCell<Self>>, Weak<RefCell<Self>>) {
+        let strong = Rc::new(RefCell::new(self));
+        let weak = Rc::downgrade(&strong);
+        (strong, weak)
+    }
+}
+
+impl<T> WeakShared for T {}
@@ -0,0 +1,75 @@
+//! Removal of one level of nesting.
+
+use core::iter::Flatten;
+
+/// Marker types which keep the impls of [`OverFlatten`](trait.OverFlatten.html) apart.
+pub mod kind {
+    /// `Option<Option<T>>`
+    pub struct Option;
+    /// `Result<Result<T, E>, E>`
+    pub struct Result;
+    /// An iterator of iterables
+    pub struct Iter;
+}
+
+/// Finish by removing one level of nesting (`Option<Option<T>> -> Option<T>`)
+///
+/// The `Kind` parameter is inferred, and only exists so that iterators can be flattened
+/// alongside `Option` and `Result`.
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(5.over(Some).over(Some).over_flatten(), Some(5));
+/// assert_eq!(Ok::<Result<i32, ()>, ()>(Err(())).over_flatten(), Err(()));
+///
+/// let v: Vec<i32> = vec![vec![1], vec![2, 3]].into_iter().over_flatten().collect();
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be flattened",
+        label = "no `over_flatten` for this type",
+        note = "`over_flatten` is implemented for `Option<Option<T>>`, `Result<Result<T, E>, E>`, and iterators of iterables"
+    )
+)]
+pub trait OverFlatten<Kind> {
+    type Output;
+
+    fn over_flatten(self) -> Self::Output;
+}
+
+impl<T> OverFlatten<kind::Option> for Option<Option<T>> {
+    type Output = Option<T>;
+
+    fn over_flatten(self) -> Option<T> {
+        self.and_then(|inner| inner)
+    }
+}
+
+impl<T, E> OverFlatten<kind::Result> for Result<Result<T, E>, E> {
+    type Output = Result<T, E>;
+
+    fn over_flatten(self) -> Result<T, E> {
+        self.and_then(|inner| inner)
+    }
+}
+
+impl<I> OverFlatten<kind::Iter> for I
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+{
+    type Output = Flatten<I>;
+
+    fn over_flatten(self) -> Flatten<I> {
+        self.flatten()
+    }
+}
+
+async_twin! {
+    /// Flatten an awaited value (`self.await |> over_flatten()`)
+    OverFlattenAwait<Kind> for OverFlatten::over_flatten => over_flatten_await
+        <>() -> <Self::Output as OverFlatten<Kind>>::Output;
+}
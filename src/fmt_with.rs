@@ -0,0 +1,101 @@
+//! Formatting a value with a closure instead of a wrapper type.
+
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A borrowed value which is displayed by a closure
+///
+/// Created with [`display_with`](trait.DisplayWith.html).
+pub struct DisplayFn<'a, T: ?Sized, F> {
+    value: &'a T,
+    f: F,
+}
+
+impl<T: ?Sized, F> Display for DisplayFn<'_, T, F>
+where
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.f)(self.value, f)
+    }
+}
+
+/// A borrowed value which is debug-formatted by a closure
+///
+/// Created with [`debug_with`](trait.DebugWith.html).
+pub struct DebugFn<'a, T: ?Sized, F> {
+    value: &'a T,
+    f: F,
+}
+
+impl<T: ?Sized, F> Debug for DebugFn<'_, T, F>
+where
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.f)(self.value, f)
+    }
+}
+
+/// Borrow the value as something which implements `Display` with a closure
+/// (`&self |> DisplayFn { value: self, f }`)
+///
+/// The closure runs each time the result is formatted, and gets the formatter's flags, so
+/// ad-hoc formatting can go straight into `format!` or `println!`.
+///
+/// ```
+/// use ov::*;
+///
+/// let bytes = [0xde, 0xad, 0xbe, 0xef];
+/// let hex = bytes.display_with(|b, f| b.iter().try_for_each(|b| write!(f, "{:02x}", b)));
+/// assert_eq!(format!("0x{}", hex), "0xdeadbeef");
+///
+/// let point = (3, -4);
+/// let shown = point.display_with(|(x, y), f| write!(f, "({}, {})", x, y));
+/// assert_eq!(shown.to_string(), "(3, -4)");
+/// ```
+pub trait DisplayWith {
+    fn display_with<F>(&self, f: F) -> DisplayFn<'_, Self, F>
+    where
+        F: Fn(&Self, &mut Formatter<'_>) -> fmt::Result,
+    {
+        DisplayFn { value: self, f }
+    }
+}
+
+impl<T: ?Sized> DisplayWith for T {}
+
+/// Borrow the value as something which implements `Debug` with a closure
+/// (`&self |> DebugFn { value: self, f }`)
+///
+/// This logs types which don't implement `Debug`, or whose `Debug` output is too noisy for
+/// one stage. The formatter's builders such as `debug_struct` work as usual, and so does
+/// `{:#?}`.
+///
+/// ```
+/// use ov::*;
+///
+/// struct Conn {
+///     host: String,
+///     password: String,
+/// }
+///
+/// let conn = Conn { host: "db.local".into(), password: "hunter2".into() };
+/// let safe = conn.debug_with(|c, f| {
+///     f.debug_struct("Conn").field("host", &c.host).finish_non_exhaustive()
+/// });
+/// assert_eq!(format!("{:?}", safe), r#"Conn { host: "db.local", .. }"#);
+///
+/// let noisy = vec![1u8; 1000];
+/// let summary = noisy.debug_with(|v, f| write!(f, "[u8; {}]", v.len()));
+/// assert_eq!(format!("{:?}", summary), "[u8; 1000]");
+/// ```
+pub trait DebugWith {
+    fn debug_with<F>(&self, f: F) -> DebugFn<'_, Self, F>
+    where
+        F: Fn(&Self, &mut Formatter<'_>) -> fmt::Result,
+    {
+        DebugFn { value: self, f }
+    }
+}
+
+impl<T: ?Sized> DebugWith for T {}
@@ -0,0 +1,67 @@
+//! Running several transformations on one value.
+
+/// Transform a clone of the receiver and the receiver itself, returning both results
+/// (`(f(self.clone()), g(self))`)
+///
+/// ```
+/// use ov::*;
+///
+/// let (words, chars) = String::from("to be or not")
+///     .over_fork(|s| s.split(' ').count(), |s| s.chars().count());
+/// assert_eq!((words, chars), (4, 12));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be forked because it doesn't implement `Clone`",
+        label = "not `Clone`",
+        note = "the first closure receives a clone; use `over_ref` to pass a shared reference to both"
+    )
+)]
+pub trait OverFork: Clone {
+    fn over_fork<F, G, A, B>(self, f: F, g: G) -> (A, B)
+    where
+        F: FnOnce(Self) -> A,
+        G: FnOnce(Self) -> B,
+    {
+        (f(self.clone()), g(self))
+    }
+}
+
+impl<T> OverFork for T where T: Clone {}
+
+/// Like [`over_fork`](trait.OverFork.html), running the two closures in parallel with
+/// `rayon::join` (`join(|| f(self.clone()), || g(self))`)
+///
+/// ```
+/// use ov::*;
+///
+/// let (min, max) = (1..=1000)
+///     .collect::<Vec<u32>>()
+///     .over_par_fork(|v| v.into_iter().min(), |v| v.into_iter().max());
+/// assert_eq!((min, max), (Some(1), Some(1000)));
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be forked across threads",
+        label = "not `Clone + Send`",
+        note = "use `over_fork` to run both closures on the current thread"
+    )
+)]
+pub trait OverParFork: Clone + Send {
+    fn over_par_fork<F, G, A, B>(self, f: F, g: G) -> (A, B)
+    where
+        F: FnOnce(Self) -> A + Send,
+        G: FnOnce(Self) -> B + Send,
+        A: Send,
+        B: Send,
+    {
+        let copy = self.clone();
+        rayon::join(move || f(copy), move || g(self))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> OverParFork for T where T: Clone + Send {}
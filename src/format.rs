@@ -0,0 +1,150 @@
+//! Stages which serialize to, and parse from, configuration formats.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Serialize to YAML and transform the text (`serde_yaml::to_string(self)? |> f`)
+///
+/// ```
+/// use ov::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Config {
+///     name: String,
+///     port: u16,
+/// }
+///
+/// let config = "name: api\nport: 80\n"
+///     .over_from_yaml(|c: Config| Config { port: c.port + 8000, ..c })
+///     .unwrap();
+/// assert_eq!(config.port, 8080);
+///
+/// let lines = config.over_to_yaml(|yaml| yaml.lines().count()).unwrap();
+/// assert_eq!(lines, 2);
+/// ```
+#[cfg(feature = "yaml")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `Serialize`, so it can't be written as YAML",
+        label = "not `Serialize`",
+        note = "derive `serde::Serialize` for the type"
+    )
+)]
+pub trait OverToYaml: Serialize {
+    fn over_to_yaml<F, Ret>(&self, f: F) -> Result<Ret, serde_yaml::Error>
+    where
+        F: FnOnce(String) -> Ret,
+    {
+        serde_yaml::to_string(self).map(f)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl<T> OverToYaml for T where T: Serialize + ?Sized {}
+
+/// Parse YAML and transform the parsed value (`serde_yaml::from_str(self)? |> f`)
+///
+/// The type to parse is usually given by annotating the closure's argument. See
+/// [`OverToYaml`](trait.OverToYaml.html) for an example.
+#[cfg(feature = "yaml")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_from_yaml` is only implemented for `str`, not `{Self}`",
+        label = "not text",
+        note = "call it on a `&str` or `String`"
+    )
+)]
+pub trait OverFromYaml {
+    fn over_from_yaml<T, F, Ret>(&self, f: F) -> Result<Ret, serde_yaml::Error>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(T) -> Ret;
+}
+
+#[cfg(feature = "yaml")]
+impl OverFromYaml for str {
+    fn over_from_yaml<T, F, Ret>(&self, f: F) -> Result<Ret, serde_yaml::Error>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(T) -> Ret,
+    {
+        serde_yaml::from_str(self).map(f)
+    }
+}
+
+/// Serialize to TOML and transform the text (`toml::to_string(self)? |> f`)
+///
+/// TOML documents are tables, so the receiver has to serialize as a struct or map.
+///
+/// ```
+/// use ov::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Config {
+///     name: String,
+///     port: u16,
+/// }
+///
+/// let config = "name = \"api\"\nport = 80\n"
+///     .over_from_toml(|c: Config| Config { port: c.port + 8000, ..c })
+///     .unwrap();
+/// assert_eq!(config.port, 8080);
+///
+/// let toml = config.over_to_toml(|toml| toml).unwrap();
+/// assert_eq!(toml, "name = \"api\"\nport = 8080\n");
+/// ```
+#[cfg(feature = "toml")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `Serialize`, so it can't be written as TOML",
+        label = "not `Serialize`",
+        note = "derive `serde::Serialize` for the type"
+    )
+)]
+pub trait OverToToml: Serialize {
+    fn over_to_toml<F, Ret>(&self, f: F) -> Result<Ret, toml::ser::Error>
+    where
+        F: FnOnce(String) -> Ret,
+    {
+        toml::to_string(self).map(f)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<T> OverToToml for T where T: Serialize + ?Sized {}
+
+/// Parse TOML and transform the parsed value (`toml::from_str(self)? |> f`)
+///
+/// The type to parse is usually given by annotating the closure's argument. See
+/// [`OverToToml`](trait.OverToToml.html) for an example.
+#[cfg(feature = "toml")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_from_toml` is only implemented for `str`, not `{Self}`",
+        label = "not text",
+        note = "call it on a `&str` or `String`"
+    )
+)]
+pub trait OverFromToml {
+    fn over_from_toml<T, F, Ret>(&self, f: F) -> Result<Ret, toml::de::Error>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(T) -> Ret;
+}
+
+#[cfg(feature = "toml")]
+impl OverFromToml for str {
+    fn over_from_toml<T, F, Ret>(&self, f: F) -> Result<Ret, toml::de::Error>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(T) -> Ret,
+    {
+        toml::from_str(self).map(f)
+    }
+}
@@ -0,0 +1,203 @@
+//! Mapping over the contents of a container.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::iter::Map;
+
+/// Transform the contents of a container (`Container<T> -> Container<U>`)
+///
+/// Generic code can accept anything mappable with a single bound. The result type is named
+/// through the associated [`Wrapped`](#associatedtype.Wrapped) type, which also takes the
+/// closure's type, since a lazily mapped iterator holds the closure. Containers ignore it, and
+/// generic code which needs to name it can pass a `fn` pointer.
+///
+/// An impl for every `Iterator` would overlap with the impls for the containers, so
+/// iterators are wrapped in [`IterFunctor`](struct.IterFunctor.html) first.
+///
+/// ```
+/// use ov::*;
+///
+/// fn describe<C>(c: C) -> C::Wrapped<String, fn(i32) -> String>
+/// where
+///     C: OverFunctor<Inner = i32>,
+/// {
+///     let label: fn(i32) -> String = |n| format!("#{}", n);
+///     c.over_inside(label)
+/// }
+///
+/// assert_eq!(describe(Some(1)), Some("#1".to_owned()));
+/// assert_eq!(describe([3]), ["#3"]);
+/// assert_eq!(describe(Err::<i32, ()>(())), Err(()));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a container which `over_inside` can map",
+        label = "not an `OverFunctor`",
+        note = "`over_inside` is implemented for `Option`, `Result`, arrays, `Vec`, `Box` and `IterFunctor`; wrap iterators with `IterFunctor::new`"
+    )
+)]
+pub trait OverFunctor {
+    /// The type of the contents
+    type Inner;
+    /// The same container holding a `U` instead of `Inner`, as mapped by an `F`
+    type Wrapped<U, F>;
+
+    fn over_inside<F, U>(self, f: F) -> Self::Wrapped<U, F>
+    where
+        F: FnMut(Self::Inner) -> U;
+}
+
+impl<T> OverFunctor for Option<T> {
+    type Inner = T;
+    type Wrapped<U, F> = Option<U>;
+
+    fn over_inside<F, U>(self, f: F) -> Option<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T, E> OverFunctor for Result<T, E> {
+    type Inner = T;
+    type Wrapped<U, F> = Result<U, E>;
+
+    fn over_inside<F, U>(self, f: F) -> Result<U, E>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T, const N: usize> OverFunctor for [T; N] {
+    type Inner = T;
+    type Wrapped<U, F> = [U; N];
+
+    fn over_inside<F, U>(self, f: F) -> [U; N]
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OverFunctor for Vec<T> {
+    type Inner = T;
+    type Wrapped<U, F> = Vec<U>;
+
+    fn over_inside<F, U>(self, f: F) -> Vec<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OverFunctor for Box<T> {
+    type Inner = T;
+    type Wrapped<U, F> = Box<U>;
+
+    fn over_inside<F, U>(self, mut f: F) -> Box<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Box::new(f(*self))
+    }
+}
+
+/// An iterator wrapped so that it implements [`OverFunctor`](trait.OverFunctor.html)
+///
+/// The wrapper is itself an iterator over the same items. `over_inside` is lazy, like
+/// `Iterator::map`: it wraps the `Map` adapter, so the closure only runs as items are
+/// consumed, and unbounded iterators can be mapped.
+///
+/// ```
+/// use ov::*;
+///
+/// fn doubled<C, F>(c: C, double: F) -> C::Wrapped<i32, F>
+/// where
+///     C: OverFunctor<Inner = i32>,
+///     F: FnMut(i32) -> i32,
+/// {
+///     c.over_inside(double)
+/// }
+///
+/// let odds = IterFunctor::new((1..=3).filter(|n| n % 2 == 1));
+/// assert_eq!(doubled(odds, |n| n * 2).collect::<Vec<_>>(), [2, 6]);
+/// assert_eq!(doubled(Some(4), |n| n * 2), Some(8));
+///
+/// let powers = IterFunctor::new(0..).over_inside(|n| 1 << n);
+/// assert_eq!(powers.take(4).collect::<Vec<_>>(), [1, 2, 4, 8]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct IterFunctor<I> {
+    iter: I,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> IterFunctor<I> {
+    pub fn new(iter: I) -> Self {
+        IterFunctor { iter }
+    }
+
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for IterFunctor<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> OverFunctor for IterFunctor<I> {
+    type Inner = I::Item;
+    type Wrapped<U, F> = IterFunctor<Map<I, F>>;
+
+    fn over_inside<F, U>(self, f: F) -> IterFunctor<Map<I, F>>
+    where
+        F: FnMut(I::Item) -> U,
+    {
+        IterFunctor::new(self.iter.map(f))
+    }
+}
+
+async_twin! {
+    /// Transform the contents of an awaited container (`self.await |> over_inside(f)`)
+    ///
+    /// The combinators which take `self` by value have async counterparts with an `_await`
+    /// suffix, like the base traits.
+    ///
+    /// ```
+    /// use ov::*;
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let fut = async { Ok::<_, String>(vec![1, 2]) }
+    ///     .over_recover_await(|_| vec![0])
+    ///     .over_inside_await(|n| n * 2);
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(pin!(fut).poll(&mut cx), Poll::Ready(vec![2, 4]));
+    /// ```
+    OverInsideAwait<> for OverFunctor::over_inside => over_inside_await
+        <F, U>(f: F) -> <Self::Output as OverFunctor>::Wrapped<U, F>
+        where { F: FnMut(<Self::Output as OverFunctor>::Inner) -> U };
+}
@@ -0,0 +1,109 @@
+//! Stages for `frunk`'s heterogeneous lists and generic representations.
+
+use frunk::generic::Generic;
+use frunk::hlist::{HCons, HMappable, HNil};
+
+/// Transform each element of an HList (`self.map(mapper)`)
+///
+/// The mapper is a `Poly` of a type implementing `Func` for every element type, a closure
+/// when the elements share a type, or an HList of closures with one per element.
+///
+/// ```
+/// use frunk::{hlist, Func, Poly};
+/// use ov::*;
+///
+/// struct Double;
+///
+/// impl Func<i32> for Double {
+///     type Output = i32;
+///     fn call(n: i32) -> i32 {
+///         n * 2
+///     }
+/// }
+///
+/// impl Func<&'static str> for Double {
+///     type Output = String;
+///     fn call(s: &'static str) -> String {
+///         s.repeat(2)
+///     }
+/// }
+///
+/// let doubled = hlist![1, "ab", 3].over_hlist(Poly(Double));
+/// assert_eq!(doubled, hlist![2, "abab".to_owned(), 6]);
+///
+/// let stepped = hlist![1, true].over_hlist(hlist![|n: i32| n + 1, |b: bool| !b]);
+/// assert_eq!(stepped, hlist![2, false]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_hlist` is only implemented for HLists, not `{Self}`",
+        label = "not an HList",
+        note = "convert a struct with `over_generic`, or build a list with `frunk::hlist!`"
+    )
+)]
+pub trait OverHList: Sized {
+    fn over_hlist<M>(self, mapper: M) -> <Self as HMappable<M>>::Output
+    where
+        Self: HMappable<M>,
+    {
+        self.map(mapper)
+    }
+}
+
+impl OverHList for HNil {}
+
+impl<H, T> OverHList for HCons<H, T> {}
+
+/// Transform through the generic representation, converting back afterwards
+/// (`Self::from(f(self.into()))`)
+///
+/// The representation is an HList of the fields, in order. `Generic` is usually derived,
+/// which needs `frunk_core` as a dependency too.
+///
+/// ```
+/// use frunk::{hlist, hlist_pat, Generic, HList};
+/// use ov::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Size {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// impl Generic for Size {
+///     type Repr = HList![u32, u32];
+///
+///     fn into(self) -> Self::Repr {
+///         hlist![self.width, self.height]
+///     }
+///
+///     fn from(hlist_pat![width, height]: Self::Repr) -> Self {
+///         Size { width, height }
+///     }
+/// }
+///
+/// let rotated = Size { width: 4, height: 3 }.over_generic(|hlist_pat![w, h]| hlist![h, w]);
+/// assert_eq!(rotated, Size { width: 3, height: 4 });
+///
+/// let scaled = rotated.over_generic(|fields| fields.over_hlist(|n| n * 10));
+/// assert_eq!(scaled, Size { width: 30, height: 40 });
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `frunk::Generic`",
+        label = "no generic representation",
+        note = "derive `frunk::Generic` for the type"
+    )
+)]
+pub trait OverGeneric: Generic + Sized {
+    fn over_generic<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Self::Repr) -> Self::Repr,
+    {
+        Self::from(f(Generic::into(self)))
+    }
+}
+
+impl<T> OverGeneric for T where T: Generic {}
@@ -0,0 +1,86 @@
+//! A global hook which observes instrumented stages.
+//!
+//! Stages run through [`over_hooked`](trait.OverHooked.html) report an [`Event`] to the
+//! hook installed with [`set`]. While no hook is installed they only check for one, and
+//! don't read the clock.
+//!
+//! ```
+//! use ov::hooks::{self, Event};
+//! use ov::*;
+//! use std::sync::{Arc, Mutex};
+//!
+//! let stages = Arc::new(Mutex::new(Vec::new()));
+//! let seen = stages.clone();
+//! hooks::set(move |event: &Event<'_>| {
+//!     seen.lock().unwrap().push(format!("{}: {} -> {}", event.stage, event.input, event.output));
+//! });
+//!
+//! let len = "abc".over_hooked("len", str::len);
+//! hooks::clear();
+//!
+//! assert_eq!(len, 3);
+//! assert_eq!(stages.lock().unwrap()[0], "len: &str -> usize");
+//! ```
+
+use std::any::type_name;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+type Hook = Arc<dyn Fn(&Event<'_>) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// One run of an instrumented stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event<'a> {
+    pub stage: &'a str,
+    /// The type name of the input, as given by `std::any::type_name`
+    pub input: &'static str,
+    /// The type name of the output, as given by `std::any::type_name`
+    pub output: &'static str,
+    pub elapsed: Duration,
+}
+
+/// Install the global hook, replacing the previous one
+pub fn set<F>(hook: F)
+where
+    F: Fn(&Event<'_>) + Send + Sync + 'static,
+{
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(hook));
+}
+
+/// Remove the global hook
+pub fn clear() {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+fn current() -> Option<Hook> {
+    // The lock is released before the hook runs, so a hook may call `set` or `clear`.
+    HOOK.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Transform by value, reporting the run to the global hook (`self |> f`)
+///
+/// See the [`hooks`](index.html) module.
+pub trait OverHooked: Sized {
+    fn over_hooked<F, Ret>(self, stage: &str, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        let hook = match current() {
+            Some(hook) => hook,
+            None => return f(self),
+        };
+        let start = Instant::now();
+        let ret = f(self);
+        hook(&Event {
+            stage,
+            input: type_name::<Self>(),
+            output: type_name::<Ret>(),
+            elapsed: start.elapsed(),
+        });
+        ret
+    }
+}
+
+impl<T> OverHooked for T {}
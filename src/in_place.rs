@@ -0,0 +1,75 @@
+//! By-value transformation behind a unique reference.
+
+use core::ptr;
+
+/// Aborts the process if dropped, which only happens when the closure unwinds
+struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        // A panic while unwinding aborts.
+        panic!("over_in_place: the closure panicked, so the value behind the reference is gone");
+    }
+}
+
+/// Transform by value through a unique reference (`*self = f(*self)`)
+///
+/// No placeholder is needed, so this works for types without `Default`. The value is moved
+/// out of the reference for the duration of `f`, which leaves nothing valid behind it if `f`
+/// panics. In that case the process aborts instead of unwinding, so the reference is never
+/// observed empty. Closures that can fail should return the value alongside the error, or use
+/// [`over_mut_transactional`](trait.OverMutTransactional.html) instead.
+///
+/// ```
+/// use ov::*;
+///
+/// enum State {
+///     Idle(String),
+///     Running { name: String, ticks: u32 },
+/// }
+///
+/// fn tick(state: &mut State) {
+///     state.over_in_place(|s| match s {
+///         State::Idle(name) => State::Running { name, ticks: 1 },
+///         State::Running { name, ticks } => State::Running { name, ticks: ticks + 1 },
+///     });
+/// }
+///
+/// let mut state = State::Idle("job".to_owned());
+/// tick(&mut state);
+/// tick(&mut state);
+/// assert!(matches!(state, State::Running { ref name, ticks: 2 } if name == "job"));
+/// ```
+///
+/// The reference is returned for further chaining:
+///
+/// ```
+/// use ov::*;
+///
+/// let mut words = vec!["b", "a"];
+/// words.over_in_place(|v| v.into_iter().rev().collect()).push("c");
+/// assert_eq!(words, ["a", "b", "c"]);
+///
+/// let mut boxed: Box<dyn Fn(i32) -> i32> = Box::new(|n| n + 1);
+/// boxed.over_in_place(|f| Box::new(move |n| f(n) * 2));
+/// assert_eq!(boxed(3), 8);
+/// ```
+pub trait OverInPlace: Sized {
+    fn over_in_place<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let guard = AbortOnUnwind;
+        // SAFETY: the value is read out and a new one written back before `self` is used
+        // again. If `f` panics, the guard aborts before anything can observe or drop the
+        // moved-out value a second time.
+        unsafe {
+            let value = ptr::read(self);
+            ptr::write(self, f(value));
+        }
+        core::mem::forget(guard);
+        self
+    }
+}
+
+impl<T> OverInPlace for T {}
@@ -0,0 +1,434 @@
+//! Iteration over collections, and iterators built from values.
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Mutate each item by unique reference (`&mut self |> for item in self`)
+///
+/// This works for any receiver whose `&mut` reference iterates over `&mut Item`, such as
+/// slices, arrays, `Vec`, and `VecDeque`. The receiver is returned for further chaining.
+///
+/// ```
+/// use ov::*;
+///
+/// let mut v = vec![3, 1, 2];
+/// v.over_each_mut(|n| *n *= 10).sort();
+/// assert_eq!(v, [10, 20, 30]);
+///
+/// let mut a = [1, 2];
+/// a[..1].over_each_mut(|n| *n = 0);
+/// assert_eq!(a, [0, 2]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`&mut {Self}` doesn't iterate over `&mut {Item}`",
+        label = "can't iterate mutably",
+        note = "`over_each_mut` needs `&mut Self: IntoIterator`; use `over_mut` to modify the value as a whole"
+    )
+)]
+pub trait OverEachMut<Item> {
+    fn over_each_mut<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnMut(&mut Item);
+}
+
+impl<T, Item> OverEachMut<Item> for T
+where
+    T: ?Sized,
+    for<'a> &'a mut T: IntoIterator<Item = &'a mut Item>,
+{
+    fn over_each_mut<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnMut(&mut Item),
+    {
+        (&mut *self).into_iter().for_each(f);
+        self
+    }
+}
+
+/// An iterator of successive applications of a function, created by
+/// [`over_iterate`](trait.OverIterate.html)
+#[derive(Debug, Clone)]
+pub struct Iterate<T, F> {
+    next: Option<T>,
+    f: F,
+}
+
+impl<T, F> Iterator for Iterate<T, F>
+where
+    F: FnMut(&T) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let item = self.next.take()?;
+        self.next = Some((self.f)(&item));
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Iterate over successive applications (`self, f(self), f(f(self)), ...`)
+///
+/// The iterator is infinite, so it's usually limited with `take` or `take_while`. Each
+/// application happens eagerly, when the previous item is yielded, since the item is moved
+/// out and can't be passed to `f` later without a `Clone` bound. So `take(n)` calls `f` `n`
+/// times, and an `f` which overflows right after the last wanted item still panics in debug
+/// builds; use wrapping or checked arithmetic there, or `take_while` on a checked step.
+///
+/// ```
+/// use ov::*;
+/// use std::panic;
+///
+/// let powers: Vec<u32> = 1.over_iterate(|n| n * 2).take(5).collect();
+/// assert_eq!(powers, [1, 2, 4, 8, 16]);
+///
+/// let collatz = 6.over_iterate(|&n| if n % 2 == 0 { n / 2 } else { 3 * n + 1 });
+/// assert_eq!(collatz.take_while(|&n| n != 1).count(), 8);
+///
+/// // The 64th item, 2^63, fits, but its successor overflows as soon as it is yielded.
+/// let overflowed = panic::catch_unwind(|| 1u64.over_iterate(|n| n * 2).take(64).count());
+/// assert_eq!(overflowed.is_err(), cfg!(debug_assertions));
+///
+/// let powers: Vec<u64> = 1u64.over_iterate(|n| n.wrapping_mul(2)).take(64).collect();
+/// assert_eq!(powers[63], 1 << 63);
+/// ```
+pub trait OverIterate: Sized {
+    fn over_iterate<F>(self, f: F) -> Iterate<Self, F>
+    where
+        F: FnMut(&Self) -> Self,
+    {
+        Iterate {
+            next: Some(self),
+            f,
+        }
+    }
+}
+
+impl<T> OverIterate for T {}
+
+/// An iterator built from a seed, created by [`over_unfold`](trait.OverUnfold.html)
+#[derive(Debug, Clone)]
+pub struct Unfold<S, F> {
+    seed: Option<S>,
+    f: F,
+}
+
+impl<S, F, Item> Iterator for Unfold<S, F>
+where
+    F: FnMut(S) -> Option<(Item, S)>,
+{
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Item> {
+        let (item, seed) = (self.f)(self.seed.take()?)?;
+        self.seed = Some(seed);
+        Some(item)
+    }
+}
+
+/// Build an iterator from a seed (`self |> f(self)? |> (item, f(seed)?) |> ...`)
+///
+/// The closure receives the current seed and returns the next item with the next seed, or
+/// `None` to end the iterator.
+///
+/// ```
+/// use ov::*;
+///
+/// let digits: Vec<u32> = 1234.over_unfold(|n| match n {
+///     0 => None,
+///     n => Some((n % 10, n / 10)),
+/// }).collect();
+/// assert_eq!(digits, [4, 3, 2, 1]);
+///
+/// let fib: Vec<u64> = (0, 1).over_unfold(|(a, b)| Some((a, (b, a + b)))).take(6).collect();
+/// assert_eq!(fib, [0, 1, 1, 2, 3, 5]);
+/// ```
+pub trait OverUnfold: Sized {
+    fn over_unfold<F, Item>(self, f: F) -> Unfold<Self, F>
+    where
+        F: FnMut(Self) -> Option<(Item, Self)>,
+    {
+        Unfold {
+            seed: Some(self),
+            f,
+        }
+    }
+}
+
+impl<T> OverUnfold for T {}
+
+/// An iterator driven by a closure over owned state, created by
+/// [`iter_with`](trait.IterWith.html)
+#[derive(Debug, Clone)]
+pub struct IterFn<S, F> {
+    state: S,
+    f: F,
+}
+
+impl<S, F> IterFn<S, F> {
+    /// Stop iterating and take back the state
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<S, F, Item> Iterator for IterFn<S, F>
+where
+    F: FnMut(&mut S) -> Option<Item>,
+{
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Item> {
+        (self.f)(&mut self.state)
+    }
+}
+
+/// Iterate by calling a closure on the receiver until it returns `None`
+/// (`iter::from_fn(move || f(&mut self))`)
+///
+/// Unlike `iter::from_fn`, the iterator owns the state, so it can be returned or stored
+/// without a `move` closure capturing it, and [`into_state`](struct.IterFn.html#method.into_state)
+/// gives it back. It isn't fused, so the closure is called again after it returns `None`.
+///
+/// ```
+/// use ov::*;
+///
+/// let words: Vec<&str> = "a  bc d".iter_with(|rest| {
+///     let s = rest.trim_start();
+///     if s.is_empty() {
+///         return None;
+///     }
+///     let end = s.find(' ').unwrap_or(s.len());
+///     let (word, tail) = s.split_at(end);
+///     *rest = tail;
+///     Some(word)
+/// }).collect();
+/// assert_eq!(words, ["a", "bc", "d"]);
+///
+/// let mut countdown = 3.iter_with(|n| {
+///     *n -= 1;
+///     Some(*n).filter(|&n| n > 0)
+/// });
+/// assert_eq!(countdown.next(), Some(2));
+/// assert_eq!(countdown.into_state(), 2);
+/// ```
+pub trait IterWith: Sized {
+    fn iter_with<F, Item>(self, f: F) -> IterFn<Self, F>
+    where
+        F: FnMut(&mut Self) -> Option<Item>,
+    {
+        IterFn { state: self, f }
+    }
+}
+
+impl<T> IterWith for T {}
+
+/// Fold an iterator into the receiver (`items.fold(self, f)`)
+///
+/// ```
+/// use ov::*;
+///
+/// let total = 10.over_fold(vec![1, 2, 3], |acc, n| acc + n).over(|n| n * 2);
+/// assert_eq!(total, 32);
+/// ```
+pub trait OverFold: Sized {
+    fn over_fold<I, F>(self, items: I, f: F) -> Self
+    where
+        I: IntoIterator,
+        F: FnMut(Self, I::Item) -> Self,
+    {
+        items.into_iter().fold(self, f)
+    }
+}
+
+impl<T> OverFold for T {}
+
+/// An iterator over the intermediate accumulators of a fold, created by
+/// [`over_scan`](trait.OverScan.html)
+#[derive(Debug, Clone)]
+pub struct Scan<A, I, F> {
+    acc: Option<A>,
+    items: I,
+    f: F,
+}
+
+impl<A, I, F> Iterator for Scan<A, I, F>
+where
+    A: Clone,
+    I: Iterator,
+    F: FnMut(A, I::Item) -> A,
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        let item = self.items.next()?;
+        let acc = (self.f)(self.acc.take()?, item);
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.acc {
+            Some(_) => self.items.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+/// Fold an iterator into the receiver, yielding every intermediate accumulator
+/// (`items.fold(self, f)`, step by step)
+///
+/// The initial value isn't yielded, so there is one accumulator per item.
+///
+/// ```
+/// use ov::*;
+///
+/// let running: Vec<i32> = 10.over_scan(vec![1, 2, 3], |acc, n| acc + n).collect();
+/// assert_eq!(running, [11, 13, 16]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be a scan accumulator because it doesn't implement `Clone`",
+        label = "not `Clone`",
+        note = "use `over_fold` if only the final accumulator is needed"
+    )
+)]
+pub trait OverScan: Clone {
+    fn over_scan<I, F>(self, items: I, f: F) -> Scan<Self, I::IntoIter, F>
+    where
+        I: IntoIterator,
+        F: FnMut(Self, I::Item) -> Self,
+    {
+        Scan {
+            acc: Some(self),
+            items: items.into_iter(),
+            f,
+        }
+    }
+}
+
+impl<T> OverScan for T where T: Clone {}
+
+/// Check each item by shared reference, passing the receiver through if every check succeeds
+/// (`for item in &self { f(item)? } |> self`)
+///
+/// Iteration stops at the first error, which is returned instead of the receiver. This works
+/// for any receiver whose `&` reference iterates over `&Item`, such as arrays, `Vec`, and
+/// maps. Use [`over_try_each_indexed`](#method.over_try_each_indexed) to also learn where
+/// the error happened.
+///
+/// ```
+/// use ov::*;
+///
+/// fn positive(n: &i32) -> Result<(), String> {
+///     if *n > 0 {
+///         Ok(())
+///     } else {
+///         Err(format!("{} isn't positive", n))
+///     }
+/// }
+///
+/// let sum: i32 = vec![1, 2, 3].over_try_each(positive).unwrap().iter().sum();
+/// assert_eq!(sum, 6);
+///
+/// assert_eq!(vec![1, -2, 0].over_try_each(positive), Err("-2 isn't positive".to_owned()));
+/// assert_eq!(vec![1, -2, 0].over_try_each_indexed(positive), Err((1, "-2 isn't positive".to_owned())));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`&{Self}` doesn't iterate over `&{Item}`",
+        label = "can't iterate by reference",
+        note = "`over_try_each` needs `&Self: IntoIterator`; use `Iterator::try_for_each` to consume an iterator"
+    )
+)]
+pub trait OverTryEach<Item>: Sized {
+    fn over_try_each<F, E>(self, f: F) -> Result<Self, E>
+    where
+        F: FnMut(&Item) -> Result<(), E>;
+
+    /// Like [`over_try_each`](#method.over_try_each), pairing the error with the index of
+    /// the item which failed
+    fn over_try_each_indexed<F, E>(self, f: F) -> Result<Self, (usize, E)>
+    where
+        F: FnMut(&Item) -> Result<(), E>;
+}
+
+impl<T, Item> OverTryEach<Item> for T
+where
+    for<'a> &'a T: IntoIterator<Item = &'a Item>,
+{
+    fn over_try_each<F, E>(self, f: F) -> Result<Self, E>
+    where
+        F: FnMut(&Item) -> Result<(), E>,
+    {
+        (&self).into_iter().try_for_each(f)?;
+        Ok(self)
+    }
+
+    fn over_try_each_indexed<F, E>(self, mut f: F) -> Result<Self, (usize, E)>
+    where
+        F: FnMut(&Item) -> Result<(), E>,
+    {
+        (&self)
+            .into_iter()
+            .enumerate()
+            .try_for_each(|(i, item)| f(item).map_err(|e| (i, e)))?;
+        Ok(self)
+    }
+}
+
+/// Run a side effect on each item in parallel by shared reference, then pass the receiver
+/// through (`self.par_iter().for_each(f) |> self`)
+///
+/// This works for any receiver whose `&` reference is a rayon parallel iterator over `&Item`,
+/// such as arrays and `Vec`. The items are visited in no particular order.
+///
+/// ```
+/// use ov::*;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// let checksum = AtomicU64::new(0);
+/// let blocks = vec![vec![1u8; 64], vec![2; 64], vec![3; 64]]
+///     .over_par_each(|block| {
+///         let sum: u64 = block.iter().map(|&b| u64::from(b)).sum();
+///         checksum.fetch_add(sum, Ordering::Relaxed);
+///     });
+/// assert_eq!(blocks.len(), 3);
+/// assert_eq!(checksum.into_inner(), 64 * 6);
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`&{Self}` isn't a parallel iterator over `&{Item}`",
+        label = "can't iterate in parallel by reference",
+        note = "`over_par_each` needs `&Self: rayon::iter::IntoParallelIterator`"
+    )
+)]
+pub trait OverParEach<Item>: Sized {
+    fn over_par_each<F>(self, f: F) -> Self
+    where
+        F: Fn(&Item) + Sync + Send;
+}
+
+#[cfg(feature = "rayon")]
+impl<T, Item> OverParEach<Item> for T
+where
+    Item: Sync,
+    for<'a> &'a T: IntoParallelIterator<Item = &'a Item>,
+{
+    fn over_par_each<F>(self, f: F) -> Self
+    where
+        F: Fn(&Item) + Sync + Send,
+    {
+        (&self).into_par_iter().for_each(f);
+        self
+    }
+}
@@ -0,0 +1,90 @@
+//! Stages which focus on part of a JSON value.
+
+use serde_json::Value;
+
+/// Transform the value at a JSON pointer, putting the result back in its place
+/// (`self[ptr] = f(self[ptr]) |> self`)
+///
+/// If the pointer doesn't resolve, the value is returned unchanged and the closure isn't
+/// called.
+///
+/// ```
+/// use ov::*;
+/// use serde_json::json;
+///
+/// let doc = json!({ "user": { "name": "ada", "logins": 2 } })
+///     .over_pointer("/user/logins", |n| json!(n.as_u64().unwrap_or(0) + 1))
+///     .over_pointer("/user/name", |name| json!(name.as_str().map(str::to_uppercase)))
+///     .over_pointer("/missing", |_| json!(null));
+///
+/// assert_eq!(doc, json!({ "user": { "name": "ADA", "logins": 3 } }));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_pointer` is only implemented for `serde_json::Value`, not `{Self}`",
+        label = "not a `serde_json::Value`",
+        note = "convert the value with `serde_json::to_value` first"
+    )
+)]
+pub trait OverPointer: Sized {
+    fn over_pointer<F>(self, pointer: &str, f: F) -> Self
+    where
+        F: FnOnce(Value) -> Value;
+}
+
+impl OverPointer for Value {
+    fn over_pointer<F>(mut self, pointer: &str, f: F) -> Self
+    where
+        F: FnOnce(Value) -> Value,
+    {
+        if let Some(target) = self.pointer_mut(pointer) {
+            let value = target.take();
+            *target = f(value);
+        }
+        self
+    }
+}
+
+/// Transform the value at a JSON pointer by unique reference
+/// (`&mut self |> self.pointer_mut(ptr).map(f)`)
+///
+/// Returns `None` without calling the closure if the pointer doesn't resolve.
+///
+/// ```
+/// use ov::*;
+/// use serde_json::json;
+///
+/// let mut doc = json!({ "tags": ["a"] });
+/// let len = doc.over_pointer_mut("/tags", |tags| {
+///     tags.as_array_mut().map(|tags| {
+///         tags.push(json!("b"));
+///         tags.len()
+///     })
+/// });
+/// assert_eq!(len, Some(Some(2)));
+/// assert_eq!(doc, json!({ "tags": ["a", "b"] }));
+/// assert_eq!(doc.over_pointer_mut("/nope", |_| ()), None);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_pointer_mut` is only implemented for `serde_json::Value`, not `{Self}`",
+        label = "not a `serde_json::Value`",
+        note = "convert the value with `serde_json::to_value` first"
+    )
+)]
+pub trait OverPointerMut {
+    fn over_pointer_mut<F, Ret>(&mut self, pointer: &str, f: F) -> Option<Ret>
+    where
+        F: FnOnce(&mut Value) -> Ret;
+}
+
+impl OverPointerMut for Value {
+    fn over_pointer_mut<F, Ret>(&mut self, pointer: &str, f: F) -> Option<Ret>
+    where
+        F: FnOnce(&mut Value) -> Ret,
+    {
+        self.pointer_mut(pointer).map(f)
+    }
+}
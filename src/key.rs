@@ -0,0 +1,184 @@
+//! Wrappers which compare, order or hash a value by a projection of it.
+//!
+//! Each wrapper stores the value with its key function, so two wrappers are only comparable
+//! when their key functions have the same type. Values wrapped by one closure, such as in
+//! `items.map(|t| t.ord_by(|t| t.priority))`, all share its type; elsewhere, bind the
+//! closure to a variable or use a `fn`.
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+
+macro_rules! key_wrapper {
+    ($name:ident) => {
+        impl<T, F> $name<T, F> {
+            /// Borrow the value, ignoring the key function
+            pub fn value(&self) -> &T {
+                &self.value
+            }
+
+            /// Unwrap the value, discarding the key function
+            pub fn into_value(self) -> T {
+                self.value
+            }
+        }
+
+        impl<T: Debug, F> Debug for $name<T, F> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.value).finish()
+            }
+        }
+
+        impl<T, F, K> PartialEq for $name<T, F>
+        where
+            F: Fn(&T) -> K,
+            K: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                (self.key)(&self.value) == (other.key)(&other.value)
+            }
+        }
+
+        impl<T, F, K> Eq for $name<T, F>
+        where
+            F: Fn(&T) -> K,
+            K: Eq,
+        {
+        }
+    };
+}
+
+/// A value which is ordered by a key, created by [`ord_by`](trait.IntoOrdBy.html)
+///
+/// Equality also uses the key, so it agrees with the ordering.
+#[derive(Clone, Copy)]
+pub struct OrdBy<T, F> {
+    value: T,
+    key: F,
+}
+
+key_wrapper!(OrdBy);
+
+impl<T, F, K> PartialOrd for OrdBy<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, F, K> Ord for OrdBy<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.key)(&self.value).cmp(&(other.key)(&other.value))
+    }
+}
+
+/// A value which is compared for equality by a key, created by [`eq_by`](trait.IntoEqBy.html)
+#[derive(Clone, Copy)]
+pub struct EqBy<T, F> {
+    value: T,
+    key: F,
+}
+
+key_wrapper!(EqBy);
+
+/// A value which is hashed and compared for equality by a key, created by
+/// [`hash_by`](trait.IntoHashBy.html)
+#[derive(Clone, Copy)]
+pub struct HashBy<T, F> {
+    value: T,
+    key: F,
+}
+
+key_wrapper!(HashBy);
+
+impl<T, F, K> Hash for HashBy<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.key)(&self.value).hash(state)
+    }
+}
+
+/// Wrap the value so it's ordered by a key (`OrdBy { value: self, key }`)
+///
+/// The key is computed on each comparison, so it should be cheap, such as a copied field. It
+/// can't borrow from the value, since one key type serves every lifetime of `&Self`.
+///
+/// ```
+/// use ov::*;
+/// use std::collections::BinaryHeap;
+///
+/// let jobs = [("lint", 2), ("deploy", 9), ("test", 5)];
+/// let mut queue: BinaryHeap<_> = jobs.iter().map(|j| j.ord_by(|j| j.1)).collect();
+///
+/// assert_eq!(queue.pop().unwrap().value().0, "deploy");
+/// assert_eq!(queue.pop().unwrap().into_value(), &("test", 5));
+/// ```
+pub trait IntoOrdBy: Sized {
+    fn ord_by<F, K>(self, key: F) -> OrdBy<Self, F>
+    where
+        F: Fn(&Self) -> K,
+        K: Ord,
+    {
+        OrdBy { value: self, key }
+    }
+}
+
+impl<T> IntoOrdBy for T {}
+
+/// Wrap the value so it's compared for equality by a key (`EqBy { value: self, key }`)
+///
+/// ```
+/// use ov::*;
+///
+/// let lower = |s: &&str| s.to_lowercase();
+/// assert!("Hello".eq_by(lower) == "HELLO".eq_by(lower));
+/// assert!("Hello".eq_by(lower) != "Help".eq_by(lower));
+///
+/// let tags = ["Rust", "rust", "Go", "RUST"];
+/// let first = tags[0].eq_by(lower);
+/// assert_eq!(tags.iter().filter(|&&t| t.eq_by(lower) == first).count(), 3);
+/// ```
+pub trait IntoEqBy: Sized {
+    fn eq_by<F, K>(self, key: F) -> EqBy<Self, F>
+    where
+        F: Fn(&Self) -> K,
+        K: PartialEq,
+    {
+        EqBy { value: self, key }
+    }
+}
+
+impl<T> IntoEqBy for T {}
+
+/// Wrap the value so it's hashed and compared for equality by a key
+/// (`HashBy { value: self, key }`)
+///
+/// ```
+/// use ov::*;
+/// use std::collections::HashSet;
+///
+/// let users = [(1, "ann"), (2, "bob"), (1, "ann (again)")];
+/// let unique: HashSet<_> = users.iter().map(|u| u.hash_by(|u| u.0)).collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+pub trait IntoHashBy: Sized {
+    fn hash_by<F, K>(self, key: F) -> HashBy<Self, F>
+    where
+        F: Fn(&Self) -> K,
+        K: Hash + Eq,
+    {
+        HashBy { value: self, key }
+    }
+}
+
+impl<T> IntoHashBy for T {}
@@ -0,0 +1,110 @@
+//! Kotlin's scope functions, for those who think in them.
+//!
+//! Kotlin distinguishes scope functions by what they return and by how the lambda sees the
+//! object: as an argument (`it`) or as the receiver (`this`). Rust has no implicit receiver,
+//! so here the receiver style gets `&mut Self`, like a method body, and the argument style
+//! gets the value itself or a shared reference.
+//!
+//! | Kotlin        | Here                    | Closure sees | Returns    | `ov` equivalent              |
+//! |---------------|-------------------------|--------------|------------|------------------------------|
+//! | `x.let { }`   | `x.let_(\|it\| ..)`     | `Self`       | the result | [`over`](../trait.Over.html) |
+//! | `x.also { }`  | `x.also(\|it\| ..)`     | `&Self`      | `Self`     | `over_ref`, then the value   |
+//! | `x.run { }`   | `x.run_(\|this\| ..)`   | `&mut Self`  | the result | `over_mut` on a binding      |
+//! | `x.apply { }` | `x.apply_(\|this\| ..)` | `&mut Self`  | `Self`     | `over_mut`, then the value   |
+//!
+//! `let`, `run` and `apply` are suffixed with `_` since the first is a keyword and the others
+//! would clash with [`aliases`](../trait.Apply.html) and common inherent methods.
+//!
+//! ```
+//! use ov::kotlin::ScopeFunctions;
+//!
+//! let mut log = Vec::new();
+//! let len = String::from("kot")
+//!     .apply_(|this| this.push_str("lin"))
+//!     .also(|it| log.push(format!("built {}", it)))
+//!     .let_(|it| it.len());
+//!
+//! assert_eq!(len, 6);
+//! assert_eq!(log, ["built kotlin"]);
+//! ```
+
+/// The four scope functions of Kotlin's standard library
+///
+/// See the [module documentation](index.html) for how they map to Kotlin and to the rest of
+/// this crate.
+pub trait ScopeFunctions: Sized {
+    /// Transform by value (`self |> f(it)`)
+    ///
+    /// ```
+    /// use ov::kotlin::ScopeFunctions;
+    ///
+    /// assert_eq!("42".let_(|it| it.parse::<i32>()), Ok(42));
+    /// ```
+    fn let_<F, Ret>(self, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        f(self)
+    }
+
+    /// Run a side effect by shared reference, then pass the value through
+    /// (`self |> f(&it) |> self`)
+    ///
+    /// ```
+    /// use ov::kotlin::ScopeFunctions;
+    ///
+    /// let mut seen = 0;
+    /// let v = vec![1, 2, 3].also(|it| seen = it.len());
+    /// assert_eq!((v, seen), (vec![1, 2, 3], 3));
+    /// ```
+    fn also<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self),
+    {
+        f(&self);
+        self
+    }
+
+    /// Transform by unique reference, as if the closure were a method (`self |> f(&mut this)`)
+    ///
+    /// The value is dropped once the closure returns, unless the closure moves it out.
+    ///
+    /// ```
+    /// use ov::kotlin::ScopeFunctions;
+    ///
+    /// let total = vec![3, 1, 2].run_(|this| {
+    ///     this.sort();
+    ///     this.push(4);
+    ///     this.iter().sum::<i32>()
+    /// });
+    /// assert_eq!(total, 10);
+    /// ```
+    fn run_<F, Ret>(mut self, f: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        f(&mut self)
+    }
+
+    /// Configure by unique reference, then pass the value through
+    /// (`self |> f(&mut this) |> self`)
+    ///
+    /// ```
+    /// use ov::kotlin::ScopeFunctions;
+    ///
+    /// let v = Vec::new().apply_(|this| {
+    ///     this.push("a");
+    ///     this.push("b");
+    /// });
+    /// assert_eq!(v, ["a", "b"]);
+    /// ```
+    fn apply_<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        f(&mut self);
+        self
+    }
+}
+
+impl<T> ScopeFunctions for T {}
@@ -9,10 +9,18 @@
 //! for types which have `Deref` and `DerefMut` implementations. They both borrow the receiver,
 //! and pass a reference of the `Deref::target` to the callback.
 //!
+//! [`PinBoxed`](trait.PinBoxed.html) is a finisher: it takes `self` and moves it into a
+//! wrapper, ending the chain.
+//!
+//! The [`def_over!`](macro.def_over.html) macro defines new traits in the same style, for
+//! adapters that are specific to your own crate.
+//!
 //! # Usage
 //!
-//! You may either wildcard import it, or import specific traits. New items will not be added
-//! in semver compatible versions.
+//! You may either wildcard import it, or import specific traits. New traits and macros can be
+//! added in semver compatible versions, so a wildcard import may bring in a method which
+//! collides with one of your own traits. Import specific traits, or the subset of the
+//! [`preludes`](preludes/index.html) for the tiers you use, if that's a concern.
 //!
 //! ```
 //! use ov::*;
@@ -94,81 +102,457 @@
 //!     .over(|r| std::mem::replace(r, Command::new(""))); // returns Command
 //!
 //! assert_type::<Command>(command);
-/// ```
-use std::ops::Deref;
-use std::ops::DerefMut;
+//! ```
+//!
+//! # Features
+//!
+//! Most traits only need `core`. Items which need more are grouped behind cargo features,
+//! so that a wildcard import only brings in the methods you opted into.
+//!
+//! - `std` (default): enables `alloc`, and items which need the standard library.
+//! - `alloc`: items which allocate, such as [`PinBoxed`](trait.PinBoxed.html).
+//! - `sync` (default): enables `std`, and items which share values between threads, such as
+//!   [`Synchronized`](trait.Synchronized.html), [`OverScoped`](trait.OverScoped.html) and
+//!   [`tap_once!`](macro.tap_once.html).
+//! - `io` (default): enables `std`, and the stages which print to stderr, such as
+//!   [`TapPretty`](trait.TapPretty.html) and [`TapTypeName`](trait.TapTypeName.html).
+//! - `macros` (default): [`def_over!`](macro.def_over.html) and the other macros. The macros of
+//!   other features, such as [`tap_once!`](macro.tap_once.html), also need it.
+//! - `aliases`: [`Pipe`](trait.Pipe.html), [`Apply`](trait.Apply.html), [`With`](trait.With.html)
+//!   and other alternative names for the traits, to ease migrating from similar crates.
+//! - `compat`: the [`compat`](compat/index.html) module, with drop-in replacements for the
+//!   traits of other crates.
+//! - `kotlin`: the [`kotlin`](kotlin/index.html) module, with Kotlin's `let`, `also`, `run`
+//!   and `apply` scope functions as [`ScopeFunctions`](kotlin/trait.ScopeFunctions.html).
+//! - `async`: [`OverAwait`](trait.OverAwait.html) and the other `_await` traits, which apply
+//!   the callback to the output of a future. By-value combinators such as `over_inside` have
+//!   them too, like [`OverInsideAwait`](trait.OverInsideAwait.html).
+//! - `nightly`: items which need a nightly compiler, such as [`OverQ`](trait.OverQ.html).
+//!   It doesn't add postfix macros, since rustc doesn't implement them on any channel, and
+//!   `postfix_match` can only be enabled by the crate which uses it. In postfix position,
+//!   [`tap_pretty`](trait.TapPretty.html), [`over_match!`](macro.over_match.html) and
+//!   [`over_let!`](macro.over_let.html) stand in for `dbg!`, `match` and `let` on stable.
+//! - `rand`: [`OverWithRng`](trait.OverWithRng.html) and [`OverRng`](trait.OverRng.html),
+//!   stages which receive a random number generator.
+//! - `defmt`: [`TapDefmt`](trait.TapDefmt.html), which logs intermediate values with `defmt`.
+//! - `critical-section`: [`OverCritical`](trait.OverCritical.html), which runs a stage inside
+//!   `critical_section::with`.
+//! - `crossbeam`: [`OverAtomicCell`](trait.OverAtomicCell.html), which updates a
+//!   `crossbeam_utils::atomic::AtomicCell` in place.
+//! - `rayon`: [`OverParChunks`](trait.OverParChunks.html) and
+//!   [`OverParWindows`](trait.OverParWindows.html), which pass blocks of a slice as parallel
+//!   iterators, [`OverParEach`](trait.OverParEach.html), which visits items in parallel, and
+//!   [`OverParFork`](trait.OverParFork.html), which runs two stages in parallel.
+//! - `unicode`: [`OverGraphemes`](trait.OverGraphemes.html) and
+//!   [`OverWords`](trait.OverWords.html), which pass the grapheme clusters or words of a
+//!   string, using `unicode-segmentation`.
+//! - `test-helpers`: the [`roundtrip`](roundtrip/index.html) module, with
+//!   [`AssertRoundtrip`](roundtrip/trait.AssertRoundtrip.html), which checks that a value
+//!   survives serialization.
+//! - `serde_json`: [`OverPointer`](trait.OverPointer.html) and
+//!   [`OverPointerMut`](trait.OverPointerMut.html), which transform the part of a
+//!   `serde_json::Value` at a JSON pointer.
+//! - `insta`: [`tap_snapshot!`](macro.tap_snapshot.html), which snapshot-tests the value in
+//!   the middle of a chain. Meant for tests.
+//! - `proptest`: the [`property`](property/index.html) module, which checks pipelines on
+//!   generated inputs with [`prop_assert_pipeline`](property/fn.prop_assert_pipeline.html)
+//!   and [`OverPropAssert`](property/trait.OverPropAssert.html). Meant for tests.
+//! - `bumpalo`: [`OverAllocIn`](trait.OverAllocIn.html) and
+//!   [`OverAllocSliceIn`](trait.OverAllocSliceIn.html), which allocate stage outputs in a
+//!   `bumpalo::Bump` arena.
+//! - `profiling`: [`over_profile_scope!`](macro.over_profile_scope.html), which runs a stage
+//!   inside a named zone of the `profiling` crate, for Tracy, puffin, Optick and other profilers.
+//! - `indicatif`: [`OverWithProgress`](trait.OverWithProgress.html) and
+//!   [`OverEachProgress`](trait.OverEachProgress.html), which advance an
+//!   `indicatif::ProgressBar` as stages and items complete.
+//! - `yaml`: [`OverToYaml`](trait.OverToYaml.html) and [`OverFromYaml`](trait.OverFromYaml.html),
+//!   which write and parse YAML with `serde_yaml`.
+//! - `toml`: [`OverToToml`](trait.OverToToml.html) and [`OverFromToml`](trait.OverFromToml.html),
+//!   which write and parse TOML with `toml`.
+//! - `regex`: [`OverCaptures`](trait.OverCaptures.html) and
+//!   [`OverCapturesIter`](trait.OverCapturesIter.html), which pass the captures of a
+//!   `regex::Regex` in a string.
+//! - `frunk`: [`OverHList`](trait.OverHList.html) and [`OverGeneric`](trait.OverGeneric.html),
+//!   which map over a `frunk` HList and round-trip a struct through its generic representation.
+//! - `futures`: [`OverForEachConcurrent`](trait.OverForEachConcurrent.html),
+//!   [`OverBuffered`](trait.OverBuffered.html) and
+//!   [`OverBufferUnordered`](trait.OverBufferUnordered.html), which run async closures on the
+//!   items of a stream or iterable with a concurrency limit.
+//!   Also [`OverRace`](trait.OverRace.html) and [`OverJoin`](trait.OverJoin.html), which race
+//!   or join two async stages on one value.
+//! - `pin-project`: [`over_project!`](macro.over_project.html), which passes the projection of a
+//!   pinned `#[pin_project]` value on, for hand-written `poll` methods.
+//! - `record`: the [`record`](record/index.html) module, which records the inputs and outputs
+//!   of stages with [`OverRecorded`](trait.OverRecorded.html) and replays them in tests.
+//! - `audit`: [`OverAudited`](trait.OverAudited.html), which sends the inputs and outputs of
+//!   stages to an audit trail as JSON [`Entry`](record/struct.Entry.html) values, and fails the
+//!   stage if the sink refuses them.
+//! - `either`: [`OverBranch`](trait.OverBranch.html), which routes a value into
+//!   `Either::Left` or `Either::Right` by a predicate.
+//! - `count-allocations`: [`OverAllocCount`](trait.OverAllocCount.html), which counts the
+//!   heap allocations made by a stage, [`OverAllocBudget`](trait.OverAllocBudget.html), which
+//!   reports stages that allocate more than expected, and the
+//!   [`CountingAllocator`](struct.CountingAllocator.html) they need. Meant for tests.
+//! - `metrics`: [`TapMetricCounter`](trait.TapMetricCounter.html) and
+//!   [`OverMetricTimer`](trait.OverMetricTimer.html), which emit counters and histograms
+//!   through the `metrics` facade.
+//! - `hooks`: the [`hooks`](hooks/index.html) module, a global hook which observes the stages
+//!   run through [`OverHooked`](hooks/trait.OverHooked.html).
+//! - `budget`: enables the measurements of [`OverTimeBudget`](trait.OverTimeBudget.html),
+//!   which reports stages that take longer than expected. Without it the stages only run
+//!   the closure.
+//!
+//! Without `std` the crate is `#![no_std]`. The [`prelude`](prelude/index.html) module
+//! re-exports every trait which is enabled, together with the enabled macros, and
+//! [`preludes`](preludes/index.html) has a module for each of `core`, `alloc`, `std`, `sync`,
+//! `io` and `macros`.
+//!
+//! With `default-features = false` nothing allocates. This tier includes the helpers for
+//! `Option`, `Result`, arrays and pairs, as well as stack-based stages such as
+//! [`OverRetry`](trait.OverRetry.html) and [`OverValidate`](trait.OverValidate.html).
+//! `cargo test --no-default-features --test no_std` checks it from a `#![no_std]` crate.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
 
-/// Transform by value (`self`)
-pub trait Over: Sized {
-    fn over<F, Ret>(self, f: F) -> Ret
-    where
-        F: FnOnce(Self) -> Ret,
-    {
-        f(self)
-    }
-}
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-impl<T> Over for T {}
+#[cfg(feature = "macros")]
+#[macro_use]
+mod macros;
+#[macro_use]
+mod maybe_async;
 
-/// Transform by shared reference (`&self`)
-pub trait OverRef {
-    fn over_ref<F, Ret>(&self, f: F) -> Ret
-    where
-        F: FnOnce(&Self) -> Ret,
-    {
-        f(self)
-    }
-}
+#[cfg(feature = "aliases")]
+mod aliases;
+#[cfg(feature = "count-allocations")]
+mod alloc_count;
+#[cfg(feature = "bumpalo")]
+mod arena;
+#[cfg(all(feature = "crossbeam", target_has_atomic = "ptr"))]
+mod atomic_cell;
+#[cfg(feature = "audit")]
+mod audit;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "either")]
+mod branch;
+mod breakpoint;
+mod budget;
+mod build;
+mod call;
+#[cfg(feature = "futures")]
+mod concurrent;
+#[cfg(target_has_atomic = "64")]
+mod counter;
+#[cfg(feature = "critical-section")]
+mod critical;
+#[cfg(feature = "defmt")]
+mod defmt_log;
+#[cfg(feature = "alloc")]
+mod diff;
+mod downcast;
+mod dynamic;
+mod env;
+#[cfg(feature = "alloc")]
+mod extract;
+#[cfg(feature = "alloc")]
+mod finish;
+pub mod flatten;
+mod fmt_with;
+mod fork;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+mod format;
+mod functor;
+#[cfg(feature = "frunk")]
+mod generic;
+#[cfg(feature = "hooks")]
+pub mod hooks;
+mod in_place;
+mod iter;
+#[cfg(feature = "serde_json")]
+mod json;
+mod key;
+#[cfg(feature = "kotlin")]
+pub mod kotlin;
+#[cfg(feature = "alloc")]
+mod logged;
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "metrics")]
+mod metric;
+mod once;
+#[cfg(feature = "std")]
+mod panic;
+mod pipeline;
+#[cfg(feature = "io")]
+mod pretty;
+mod profile;
+#[cfg(feature = "indicatif")]
+mod progress;
+#[cfg(all(feature = "pin-project", feature = "macros"))]
+mod project;
+#[cfg(feature = "proptest")]
+pub mod property;
+#[cfg(feature = "nightly")]
+mod question;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(any(feature = "record", feature = "audit"))]
+pub mod record;
+mod result;
+mod retry;
+#[cfg(feature = "test-helpers")]
+pub mod roundtrip;
+mod select;
+mod slice;
+#[cfg(all(feature = "insta", feature = "macros"))]
+mod snapshot;
+#[cfg(feature = "std")]
+mod spy;
+mod state;
+pub mod strict;
+#[cfg(feature = "alloc")]
+mod string;
+mod text;
+#[cfg(feature = "sync")]
+mod thread;
+mod transaction;
+mod tuple;
+mod types;
+mod uninit;
+mod validate;
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+mod weak;
+#[cfg(all(feature = "profiling", feature = "macros"))]
+mod zone;
 
-impl<T> OverRef for T {}
+#[cfg(feature = "compat")]
+pub mod compat;
+pub mod prelude;
+pub mod preludes;
 
-/// Transform by unique reference (`&mut self`)
-pub trait OverMut {
-    fn over_mut<F, Ret>(&mut self, f: F) -> Ret
-    where
-        F: FnOnce(&mut Self) -> Ret,
-    {
-        f(self)
-    }
-}
+#[cfg(feature = "aliases")]
+pub use aliases::{Apply, Pipe, PipeDeref, PipeDerefMut, PipeMut, PipeRef, With};
+#[cfg(feature = "count-allocations")]
+pub use alloc_count::{AllocCount, CountingAllocator, OverAllocBudget, OverAllocCount};
+#[cfg(feature = "bumpalo")]
+pub use arena::{OverAllocIn, OverAllocSliceIn};
+#[cfg(all(feature = "crossbeam", target_has_atomic = "ptr"))]
+pub use atomic_cell::OverAtomicCell;
+#[cfg(feature = "audit")]
+pub use audit::{AuditError, AuditSink, OverAudited};
+#[cfg(feature = "alloc")]
+pub use boxed::OverMapBox;
+#[cfg(feature = "either")]
+pub use branch::OverBranch;
+pub use breakpoint::TapBreakpoint;
+pub use budget::OverTimeBudget;
+pub use build::BuildWith;
+#[cfg(feature = "alloc")]
+pub use call::OverBoxed;
+pub use call::{OverCall, OverCallMut};
+#[cfg(feature = "futures")]
+pub use concurrent::{
+    FromIter, FromStream, IntoStream, OverBufferUnordered, OverBuffered, OverForEachConcurrent,
+    OverJoin, OverJoinRef, OverRace,
+};
+#[cfg(all(target_has_atomic = "64", feature = "sync"))]
+pub use counter::Counters;
+#[cfg(target_has_atomic = "64")]
+pub use counter::{Counter, Increment, OverCounted};
+#[cfg(feature = "critical-section")]
+pub use critical::OverCritical;
+#[cfg(feature = "defmt")]
+pub use defmt_log::{DefmtLevel, TapDefmt};
+#[cfg(feature = "alloc")]
+pub use diff::{DebugDiff, OverMutDiff};
+pub use downcast::OverDowncast;
+pub use dynamic::OverDyn;
+pub use env::{OverEnv, WithEnv};
+#[cfg(feature = "alloc")]
+pub use extract::OverExtractIf;
+#[cfg(feature = "alloc")]
+pub use finish::{OverLeak, OverLeakRef, PinBoxed, Shared, WeakShared};
+#[cfg(feature = "sync")]
+pub use finish::{RwLocked, Synchronized};
+pub use flatten::OverFlatten;
+#[cfg(feature = "async")]
+pub use flatten::OverFlattenAwait;
+pub use fmt_with::{DebugFn, DebugWith, DisplayFn, DisplayWith};
+pub use fork::OverFork;
+#[cfg(feature = "rayon")]
+pub use fork::OverParFork;
+#[cfg(feature = "toml")]
+pub use format::{OverFromToml, OverToToml};
+#[cfg(feature = "yaml")]
+pub use format::{OverFromYaml, OverToYaml};
+#[cfg(feature = "alloc")]
+pub use functor::IterFunctor;
+pub use functor::OverFunctor;
+#[cfg(feature = "async")]
+pub use functor::OverInsideAwait;
+#[cfg(feature = "frunk")]
+pub use generic::{OverGeneric, OverHList};
+#[cfg(feature = "hooks")]
+pub use hooks::OverHooked;
+pub use in_place::OverInPlace;
+#[cfg(feature = "rayon")]
+pub use iter::OverParEach;
+pub use iter::{
+    IterFn, IterWith, Iterate, OverEachMut, OverFold, OverIterate, OverScan, OverTryEach,
+    OverUnfold, Scan, Unfold,
+};
+#[cfg(feature = "serde_json")]
+pub use json::{OverPointer, OverPointerMut};
+pub use key::{EqBy, HashBy, IntoEqBy, IntoHashBy, IntoOrdBy, OrdBy};
+#[cfg(feature = "kotlin")]
+pub use kotlin::ScopeFunctions;
+#[cfg(feature = "alloc")]
+pub use logged::{Logged, OverLogged};
+#[cfg(feature = "alloc")]
+pub use map::{OverMapKeys, OverMapValues};
+#[cfg(feature = "metrics")]
+pub use metric::{OverMetricTimer, TapMetricCounter};
+pub use once::{OverGetOrInit, TapInit};
+#[cfg(feature = "std")]
+pub use panic::OverCatchMsg;
+pub use pipeline::Ov;
+#[cfg(feature = "io")]
+pub use pretty::TapPretty;
+pub use profile::{OverDebugOnly, OverReleaseOnly};
+#[cfg(feature = "indicatif")]
+pub use progress::{OverEachProgress, OverWithProgress};
+#[cfg(feature = "proptest")]
+pub use property::OverPropAssert;
+#[cfg(feature = "nightly")]
+pub use question::OverQ;
+#[cfg(feature = "rand")]
+pub use random::{OverRng, OverWithRng};
+#[cfg(feature = "record")]
+pub use record::{OverRecorded, Recorder};
+pub use result::{OverOrElseWith, OverRecover};
+#[cfg(feature = "async")]
+pub use result::{OverOrElseWithAwait, OverRecoverAwait};
+pub use retry::OverRetry;
+#[cfg(feature = "test-helpers")]
+pub use roundtrip::AssertRoundtrip;
+pub use select::{OverMaxBy, OverMaxWith, OverMinBy, OverMinWith};
+pub use slice::{OverChunks, OverWindows};
+#[cfg(feature = "rayon")]
+pub use slice::{OverParChunks, OverParWindows};
+#[cfg(feature = "std")]
+pub use spy::{Spy, TapSpy};
+pub use state::{OverState, WithState};
+#[cfg(feature = "alloc")]
+pub use string::{OverPushStr, OverReplaced, OverTrimmed};
+pub use text::OverLines;
+#[cfg(feature = "regex")]
+pub use text::{OverCaptures, OverCapturesIter};
+#[cfg(feature = "unicode")]
+pub use text::{OverGraphemes, OverWords};
+#[cfg(feature = "sync")]
+pub use thread::OverScoped;
+pub use transaction::OverMutTransactional;
+pub use tuple::{OverFirst, OverSecond};
+#[cfg(feature = "async")]
+pub use tuple::{OverFirstAwait, OverSecondAwait};
+pub use types::{ExpectType, IsSameAs};
+#[cfg(feature = "io")]
+pub use types::{TapLayout, TapLayoutVal, TapTypeName};
+pub use uninit::{OverInit, OverInitUnchecked, TryOverInit};
+pub use validate::OverValidate;
+#[cfg(feature = "async")]
+pub use validate::OverValidateAwait;
+#[cfg(feature = "alloc")]
+pub use vec::{OverDeduped, OverDedupedByKey, OverReversed, OverSorted, OverSortedByKey};
+#[cfg(feature = "alloc")]
+pub use weak::{OverDowngrade, OverUpgradeWith};
 
-impl<T> OverMut for T {}
+#[cfg(all(feature = "insta", feature = "macros"))]
+#[doc(hidden)]
+pub use insta as __insta;
+#[cfg(all(feature = "profiling", feature = "macros"))]
+#[doc(hidden)]
+pub use profiling as __profiling;
+#[cfg(all(feature = "sync", feature = "macros"))]
+#[doc(hidden)]
+pub use std::sync::Once as __Once;
 
-/// Transform by deref coercion (`&self |> Deref::deref(self)`)
-pub trait OverDeref: Deref {
-    fn over_deref<F, Ret>(&self, f: F) -> Ret
-    where
-        F: FnOnce(&<Self as Deref>::Target) -> Ret;
-}
+maybe_async! {
+    /// Transform by value (`self`)
+    Over::over,
+    /// Transform an awaited value by value (`self.await`)
+    ///
+    /// Each of the five traits has an async counterpart with an `_await` suffix. These are
+    /// implemented for futures and return a future of the callback's result.
+    ///
+    /// ```
+    /// use ov::*;
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let fut = async { String::from("abc") }
+    ///     .over_deref_await(str::len)
+    ///     .over_await(|len| len * 2);
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(pin!(fut).poll(&mut cx), Poll::Ready(6));
+    /// ```
+    OverAwait::over_await => value;
 
-impl<T> OverDeref for T
-where
-    T: Deref,
-{
-    fn over_deref<F, Ret>(&self, f: F) -> Ret
-    where
-        F: FnOnce(&Self::Target) -> Ret,
-    {
-        f(Deref::deref(self))
-    }
-}
+    /// Transform by shared reference (`&self`)
+    OverRef::over_ref,
+    /// Transform an awaited value by shared reference (`&self.await`)
+    OverRefAwait::over_ref_await => ref;
 
-/// Transform by mutable deref coercion (`&mut self |> DerefMut::deref_mut(self)`)
-pub trait OverDerefMut: DerefMut {
-    fn over_deref_mut<F, Ret>(&mut self, f: F) -> Ret
-    where
-        F: FnOnce(&mut Self::Target) -> Ret;
-}
+    /// Transform by unique reference (`&mut self`)
+    OverMut::over_mut,
+    /// Transform an awaited value by unique reference (`&mut self.await`)
+    OverMutAwait::over_mut_await => mut;
+
+    /// Transform by deref coercion (`&self |> Deref::deref(self)`)
+    #[cfg_attr(
+        ov_diagnostic,
+        diagnostic::on_unimplemented(
+            message = "`{Self}` doesn't implement `Deref`, so it can't be passed to `over_deref`",
+            label = "not `Deref`",
+            note = "use `over_ref` to pass a reference to the value itself"
+        )
+    )]
+    OverDeref::over_deref,
+    /// Transform an awaited value by deref coercion (`&self.await |> Deref::deref`)
+    #[cfg_attr(
+        ov_diagnostic,
+        diagnostic::on_unimplemented(
+            message = "the output of `{Self}` doesn't implement `Deref`",
+            label = "output isn't `Deref`",
+            note = "use `over_ref_await` to pass a reference to the output itself"
+        )
+    )]
+    OverDerefAwait::over_deref_await => deref;
 
-impl<T, DerefTarget> OverDerefMut for T
-where
-    T: DerefMut,
-    T: Deref<Target = DerefTarget>,
-{
-    fn over_deref_mut<F, Ret>(&mut self, f: F) -> Ret
-    where
-        F: FnOnce(&mut DerefTarget) -> Ret,
-    {
-        f(DerefMut::deref_mut(self))
-    }
+    /// Transform by mutable deref coercion (`&mut self |> DerefMut::deref_mut(self)`)
+    #[cfg_attr(
+        ov_diagnostic,
+        diagnostic::on_unimplemented(
+            message = "`{Self}` doesn't implement `DerefMut`, so it can't be passed to `over_deref_mut`",
+            label = "not `DerefMut`",
+            note = "use `over_mut` to pass a unique reference to the value itself"
+        )
+    )]
+    OverDerefMut::over_deref_mut,
+    /// Transform an awaited value by mutable deref coercion (`&mut self.await |> DerefMut::deref_mut`)
+    #[cfg_attr(
+        ov_diagnostic,
+        diagnostic::on_unimplemented(
+            message = "the output of `{Self}` doesn't implement `DerefMut`",
+            label = "output isn't `DerefMut`",
+            note = "use `over_mut_await` to pass a unique reference to the output itself"
+        )
+    )]
+    OverDerefMutAwait::over_deref_mut_await => deref_mut;
 }
@@ -0,0 +1,93 @@
+//! Pipelines which collect log entries from their stages.
+
+use alloc::vec::Vec;
+
+/// A value together with the entries logged by the stages which produced it
+///
+/// Created with [`over_logged`](trait.OverLogged.html). [`over`](#method.over) records an
+/// entry for the stage it runs, and [`over_with`](#method.over_with) lets the stage append
+/// any number of entries itself. The entries may be strings or any other type.
+///
+/// ```
+/// use ov::*;
+///
+/// let (value, log) = 4
+///     .over_logged()
+///     .over("double", |n| n * 2)
+///     .over_with(|n, log| {
+///         if n > 5 {
+///             log.push("clamp");
+///         }
+///         n.min(5)
+///     })
+///     .into_parts();
+///
+/// assert_eq!(value, 5);
+/// assert_eq!(log, ["double", "clamp"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Logged<L, T> {
+    value: T,
+    log: Vec<L>,
+}
+
+impl<L, T> Logged<L, T> {
+    pub fn new(value: T) -> Self {
+        Logged {
+            value,
+            log: Vec::new(),
+        }
+    }
+
+    /// Run a stage, logging `entry` for it
+    pub fn over<F, U>(mut self, entry: L, f: F) -> Logged<L, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.log.push(entry);
+        self.over_with(|value, _| f(value))
+    }
+
+    /// Run a stage, passing it the log to append to
+    pub fn over_with<F, U>(self, f: F) -> Logged<L, U>
+    where
+        F: FnOnce(T, &mut Vec<L>) -> U,
+    {
+        let Logged { value, mut log } = self;
+        let value = f(value, &mut log);
+        Logged { value, log }
+    }
+
+    /// Append an entry without running a stage
+    pub fn log(mut self, entry: L) -> Self {
+        self.log.push(entry);
+        self
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn entries(&self) -> &[L] {
+        &self.log
+    }
+
+    /// Finish the pipeline, returning the value and the log
+    pub fn into_parts(self) -> (T, Vec<L>) {
+        (self.value, self.log)
+    }
+
+    /// Finish the pipeline, discarding the log
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+/// Start a pipeline with a log (`self |> Logged::new(self)`)
+pub trait OverLogged: Sized {
+    fn over_logged<L>(self) -> Logged<L, Self> {
+        Logged::new(self)
+    }
+}
+
+impl<T> OverLogged for T {}
@@ -0,0 +1,619 @@
+//! Macros for defining ov-style traits outside of this crate.
+
+/// Define an ov-style trait and its blanket impl
+///
+/// The trait has a single method which takes a callback. The method body computes the
+/// value passed to the callback, in the same way that [`OverDeref`](trait.OverDeref.html)
+/// passes `Deref::deref(self)`. The receiver may be `self`, `&self`, or `&mut self`, and
+/// the trait's bounds become the bounds of the blanket impl.
+///
+/// ```
+/// use ov::def_over;
+///
+/// def_over! {
+///     /// Transform by string slice (`&self |> AsRef::as_ref(self)`)
+///     pub trait OverStr: AsRef<str> {
+///         fn over_str(&self) -> &str {
+///             self.as_ref()
+///         }
+///     }
+/// }
+///
+/// let len = String::from("abc").over_str(str::len);
+/// assert_eq!(len, 3);
+/// ```
+///
+/// A by-value receiver consumes `self`, and a trait without bounds is implemented for all
+/// types.
+///
+/// ```
+/// # use ov::def_over;
+/// def_over! {
+///     /// Transform by `Vec<u8>` (`self |> Into::into(self)`)
+///     pub trait OverBytes: Into<Vec<u8>> {
+///         fn over_bytes(self) -> Vec<u8> {
+///             self.into()
+///         }
+///     }
+/// }
+///
+/// def_over! {
+///     /// Transform by layout (`&mut self |> (size_of_val(self), align_of_val(self))`)
+///     trait OverLayout {
+///         fn over_layout(&mut self) -> (usize, usize) {
+///             (std::mem::size_of_val(self), std::mem::align_of_val(self))
+///         }
+///     }
+/// }
+///
+/// assert_eq!("hi".over_bytes(|b| b.len()), 2);
+/// assert_eq!(0u32.over_layout(|(size, _)| size), 4);
+/// ```
+#[macro_export]
+macro_rules! def_over {
+    (
+        $(#[$attr:meta])*
+        $vis:vis trait $name:ident { $($item:tt)* }
+    ) => {
+        $crate::def_over!(@trait [$(#[$attr])*] [$vis] $name [] { $($item)* });
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis trait $name:ident : $($rest:tt)+
+    ) => {
+        $crate::def_over!(@bounds [$(#[$attr])*] [$vis] $name [] $($rest)+);
+    };
+
+    // Collect the bounds up to the trait body, which is the last token tree.
+    (@bounds $attrs:tt $vis:tt $name:ident [$($bound:tt)*] { $($item:tt)* }) => {
+        $crate::def_over!(@trait $attrs $vis $name [$($bound)*] { $($item)* });
+    };
+    (@bounds $attrs:tt $vis:tt $name:ident [$($bound:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::def_over!(@bounds $attrs $vis $name [$($bound)* $next] $($rest)+);
+    };
+
+    (@trait [$($attr:tt)*] [$($vis:tt)*] $name:ident [$($bound:tt)*] {
+        $(#[$method_attr:meta])*
+        fn $method:ident(&mut $this:ident) -> $arg:ty $body:block
+    }) => {
+        $($attr)*
+        $($vis)* trait $name
+        where
+            Self: $($bound)*
+        {
+            $(#[$method_attr])*
+            fn $method<F, Ret>(&mut self, f: F) -> Ret
+            where
+                F: FnOnce($arg) -> Ret;
+        }
+
+        impl<T: ?Sized> $name for T
+        where
+            T: $($bound)*
+        {
+            fn $method<F, Ret>(&mut $this, f: F) -> Ret
+            where
+                F: FnOnce($arg) -> Ret,
+            {
+                f($body)
+            }
+        }
+    };
+    (@trait [$($attr:tt)*] [$($vis:tt)*] $name:ident [$($bound:tt)*] {
+        $(#[$method_attr:meta])*
+        fn $method:ident(&$this:ident) -> $arg:ty $body:block
+    }) => {
+        $($attr)*
+        $($vis)* trait $name
+        where
+            Self: $($bound)*
+        {
+            $(#[$method_attr])*
+            fn $method<F, Ret>(&self, f: F) -> Ret
+            where
+                F: FnOnce($arg) -> Ret;
+        }
+
+        impl<T: ?Sized> $name for T
+        where
+            T: $($bound)*
+        {
+            fn $method<F, Ret>(&$this, f: F) -> Ret
+            where
+                F: FnOnce($arg) -> Ret,
+            {
+                f($body)
+            }
+        }
+    };
+    (@trait [$($attr:tt)*] [$($vis:tt)*] $name:ident [$($bound:tt)*] {
+        $(#[$method_attr:meta])*
+        fn $method:ident($this:ident) -> $arg:ty $body:block
+    }) => {
+        $($attr)*
+        $($vis)* trait $name
+        where
+            Self: Sized + $($bound)*
+        {
+            $(#[$method_attr])*
+            fn $method<F, Ret>(self, f: F) -> Ret
+            where
+                F: FnOnce($arg) -> Ret;
+        }
+
+        impl<T> $name for T
+        where
+            T: $($bound)*
+        {
+            fn $method<F, Ret>($this, f: F) -> Ret
+            where
+                F: FnOnce($arg) -> Ret,
+            {
+                f($body)
+            }
+        }
+    };
+}
+
+/// Implement the opt-in [`strict::Over`](strict/trait.Over.html),
+/// [`strict::OverRef`](strict/trait.OverRef.html) and
+/// [`strict::OverMut`](strict/trait.OverMut.html) for the given types
+///
+/// Generic types can opt in with empty impls, such as
+/// `impl<T> ov::strict::Over for Wrapper<T> {}`.
+///
+/// ```
+/// use ov::impl_over;
+/// use ov::strict::{Over, OverMut, OverRef};
+///
+/// struct Meters(f64);
+/// struct Feet(f64);
+///
+/// impl_over!(Meters, Feet);
+///
+/// let mut m = Meters(2.0);
+/// m.over_mut(|m| m.0 += 1.0);
+/// assert_eq!(m.over_ref(|m| m.0), 3.0);
+/// assert_eq!(Feet(10.0).over(|f| f.0 / 2.0), 5.0);
+/// ```
+#[macro_export]
+macro_rules! impl_over {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::strict::Over for $ty {}
+            impl $crate::strict::OverRef for $ty {}
+            impl $crate::strict::OverMut for $ty {}
+        )+
+    };
+}
+
+/// A closure which matches its argument against the given arms
+///
+/// `over_match!(arms)` is `|value| match value { arms }`, so the arms are checked for
+/// exhaustiveness where they are written.
+///
+/// Crates on nightly can write `value.match { arms }` with `#![feature(postfix_match)]`.
+/// A library can't enable that feature for its users, and postfix macros aren't available
+/// on any compiler, so this closure form is what `ov` provides.
+///
+/// ```
+/// use ov::*;
+///
+/// let n = Some(4).over(over_match! {
+///     Some(n) if n > 10 => n,
+///     Some(n) => n + 1,
+///     None => 0,
+/// });
+/// assert_eq!(n, 5);
+///
+/// let label = (3, "x").over(over_match!((0, _) => "empty", (_, name) => name));
+/// assert_eq!(label, "x");
+/// ```
+#[macro_export]
+macro_rules! over_match {
+    ($($arms:tt)*) => {
+        |value| match value {
+            $($arms)*
+        }
+    };
+}
+
+/// Extract a pattern from a value, or diverge
+///
+/// `over_let!(pat = value => out, else diverge)` is
+/// `{ let pat = value else { diverge }; out }`. Like `let ... else`, the `else` expression
+/// must diverge, such as with `return`, `break` or `continue`. Unlike it, the whole thing
+/// is an expression, so the chain can continue on the result.
+///
+/// ```
+/// use ov::*;
+///
+/// fn first_even(items: &[&str]) -> Option<u32> {
+///     for item in items {
+///         let n = over_let!(Ok(n) = item.parse::<u32>() => n, else continue).over(|n| n * 10);
+///         if n % 20 == 0 {
+///             return Some(n);
+///         }
+///     }
+///     None
+/// }
+///
+/// assert_eq!(first_even(&["x", "3", "4"]), Some(40));
+/// assert_eq!(first_even(&["x"]), None);
+/// ```
+#[macro_export]
+macro_rules! over_let {
+    ($pat:pat = $value:expr => $out:expr, else $else:expr $(,)?) => {{
+        let $pat = $value else { $else };
+        $out
+    }};
+}
+
+/// Pipe a value through closure-like stages which may use `?`
+///
+/// `try_pipe!(value => |a| f(a)? => |b| g(b))` is `{ let a = value; let b = f(a)?; g(b) }`.
+/// The stages are written like closures but expanded inline, so `?`, `return`, `break` and
+/// `continue` apply to the enclosing function or loop.
+///
+/// ```
+/// use ov::*;
+/// use std::num::ParseIntError;
+///
+/// fn parse_port(input: &str) -> Result<u16, ParseIntError> {
+///     let port = try_pipe!(input
+///         => |s| s.trim()
+///         => |s| s.parse::<u16>()?
+///         => |port| port.max(1024));
+///     Ok(port)
+/// }
+///
+/// assert_eq!(parse_port(" 80 "), Ok(1024));
+/// assert!(parse_port("http").is_err());
+/// ```
+#[macro_export]
+macro_rules! try_pipe {
+    ($value:expr $(=> |$arg:pat_param| $body:expr)* $(,)?) => {{
+        let value = $value;
+        $(
+            let $arg = value;
+            let value = $body;
+        )*
+        value
+    }};
+}
+
+/// Pipe a value through functions with `|>` syntax
+///
+/// Each stage after a `|>` is either a callable, which is called with the value, or a
+/// call whose arguments contain a `_` placeholder, which is replaced by the value. A
+/// parenthesized stage, such as a closure, is a callable. So
+/// `chain!(x |> f |> g(_, 1))` is `g(f(x), 1)`.
+///
+/// ```
+/// use ov::*;
+///
+/// fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// fn scale(by: i32) -> impl Fn(i32) -> i32 {
+///     move |n| n * by
+/// }
+///
+/// let n = chain!("5" |> str::parse::<i32> |> Result::unwrap |> add(_, 2) |> scale(3) |> (|n| n - 1));
+/// assert_eq!(n, 20);
+/// ```
+///
+/// A parenthesized closure is always called with the value, even when its pattern is `_`:
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(chain!(5 |> (|_| 0)), 0);
+/// assert_eq!(chain!(5 |> (|_: i32| "ignored") |> str::len), 7);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    // Collect the initial value, up to the first `|>`.
+    (@value [$($value:tt)*] | > $($rest:tt)*) => {
+        $crate::chain!(@stage ($($value)*) [] $($rest)*)
+    };
+    (@value [$($value:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::chain!(@value [$($value)* $next] $($rest)*)
+    };
+    (@value [$($value:tt)*]) => {
+        $($value)*
+    };
+
+    // Collect each stage, up to the next `|>`, and apply it to the value so far.
+    (@stage $acc:tt [$($stage:tt)*] | > $($rest:tt)*) => {
+        $crate::chain!(@stage ({
+            let value = $acc;
+            $crate::chain!(@apply value [] $($stage)*)
+        }) [] $($rest)*)
+    };
+    (@stage $acc:tt [$($stage:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::chain!(@stage $acc [$($stage)* $next] $($rest)*)
+    };
+    (@stage $acc:tt [$($stage:tt)*]) => {{
+        let value = $acc;
+        $crate::chain!(@apply value [] $($stage)*)
+    }};
+
+    // A parenthesized stage on its own is a callable, such as `(|_| 0)`, otherwise a stage
+    // ending in an argument list may contain the placeholder.
+    (@apply $value:ident [] ($($callable:tt)*)) => {
+        ($($callable)*)($value)
+    };
+    (@apply $value:ident [$($callee:tt)+] ($($args:tt)*)) => {
+        $crate::chain!(@args $value [$($callee)*] [] no $($args)*)
+    };
+    (@apply $value:ident [$($callee:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::chain!(@apply $value [$($callee)* $next] $($rest)+)
+    };
+    (@apply $value:ident [$($callee:tt)*] $last:tt) => {
+        ($($callee)* $last)($value)
+    };
+
+    (@args $value:ident $callee:tt [$($args:tt)*] $found:ident _ $($rest:tt)*) => {
+        $crate::chain!(@args $value $callee [$($args)* $value] yes $($rest)*)
+    };
+    (@args $value:ident $callee:tt [$($args:tt)*] $found:ident $next:tt $($rest:tt)*) => {
+        $crate::chain!(@args $value $callee [$($args)* $next] $found $($rest)*)
+    };
+    (@args $value:ident [$($callee:tt)*] [$($args:tt)*] yes) => {
+        $($callee)*($($args)*)
+    };
+    (@args $value:ident [$($callee:tt)*] [$($args:tt)*] no) => {
+        ($($callee)*($($args)*))($value)
+    };
+
+    ($($tokens:tt)+) => {
+        $crate::chain!(@value [] $($tokens)+)
+    };
+}
+
+/// Thread a value through calls as their first argument
+///
+/// `thread_first!(x, f(a), g(b))` is `g(f(x, a), b)`, like Clojure's `->`. A stage without
+/// an argument list, such as a path or a parenthesized closure, is called with just the
+/// value. See [`thread_last!`](macro.thread_last.html) to insert it last instead, and
+/// [`chain!`](macro.chain.html) to place it anywhere.
+///
+/// ```
+/// use ov::*;
+///
+/// fn clamp(n: i32, lo: i32, hi: i32) -> i32 {
+///     n.max(lo).min(hi)
+/// }
+///
+/// let n = thread_first!("42", str::parse::<i32>, Result::unwrap, clamp(0, 10), (|n| n * 2));
+/// assert_eq!(n, 20);
+///
+/// let n = thread_first!("x", str::parse::<i32>, Result::ok, Option::unwrap_or(-1));
+/// assert_eq!(n, -1);
+/// ```
+#[macro_export]
+macro_rules! thread_first {
+    // Collect each stage, up to the next comma, and apply it to the value so far.
+    (@stage $pos:ident $acc:tt [$($stage:tt)*] , $($rest:tt)*) => {
+        $crate::thread_first!(@stage $pos ({
+            let value = $acc;
+            $crate::thread_first!(@apply $pos value [] $($stage)*)
+        }) [] $($rest)*)
+    };
+    (@stage $pos:ident $acc:tt [$($stage:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::thread_first!(@stage $pos $acc [$($stage)* $next] $($rest)*)
+    };
+    (@stage $pos:ident $acc:tt []) => {
+        $acc
+    };
+    (@stage $pos:ident $acc:tt [$($stage:tt)+]) => {{
+        let value = $acc;
+        $crate::thread_first!(@apply $pos value [] $($stage)+)
+    }};
+
+    // A parenthesized stage on its own is a callable, otherwise a trailing argument list
+    // receives the value.
+    (@apply $pos:ident $value:ident [] ($($callable:tt)*)) => {
+        ($($callable)*)($value)
+    };
+    (@apply first $value:ident [$($callee:tt)+] ($($args:tt)*)) => {
+        $($callee)+($value, $($args)*)
+    };
+    (@apply last $value:ident [$($callee:tt)+] ()) => {
+        $($callee)+($value)
+    };
+    (@apply last $value:ident [$($callee:tt)+] ($($args:tt)+)) => {
+        $($callee)+($($args)+, $value)
+    };
+    (@apply $pos:ident $value:ident [$($callee:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::thread_first!(@apply $pos $value [$($callee)* $next] $($rest)+)
+    };
+    (@apply $pos:ident $value:ident [$($callee:tt)*] $last:tt) => {
+        ($($callee)* $last)($value)
+    };
+
+    ($value:expr $(, $($stages:tt)*)?) => {
+        $crate::thread_first!(@stage first ($value) [] $($($stages)*)?)
+    };
+}
+
+/// Thread a value through calls as their last argument
+///
+/// `thread_last!(x, f(a), g(b))` is `g(b, f(a, x))`, like Clojure's `->>`. Stages are
+/// written as for [`thread_first!`](macro.thread_first.html).
+///
+/// ```
+/// use ov::*;
+///
+/// fn map_all(f: fn(i32) -> i32, v: Vec<i32>) -> Vec<i32> {
+///     v.into_iter().map(f).collect()
+/// }
+///
+/// fn take(n: usize, v: Vec<i32>) -> Vec<i32> {
+///     v.into_iter().take(n).collect()
+/// }
+///
+/// let v = thread_last!((1..=10).collect(), map_all(|n| n * n), take(3));
+/// assert_eq!(v, [1, 4, 9]);
+/// ```
+#[macro_export]
+macro_rules! thread_last {
+    ($value:expr $(, $($stages:tt)*)?) => {
+        $crate::thread_first!(@stage last ($value) [] $($($stages)*)?)
+    };
+}
+
+/// Call several methods on one value, returning the value
+///
+/// `cascade!(value => .a(x); .b(y))` is `{ let mut v = value; v.a(x); v.b(y); v }`, so the
+/// methods may return `&mut Self`, `()` or anything else, and their results are ignored.
+///
+/// ```
+/// use ov::*;
+/// use std::process::Command;
+///
+/// let command = cascade!(Command::new("ls") => .arg("-a"); .arg("-l"); .env("LANG", "C"));
+/// assert_eq!(command.get_args().count(), 2);
+///
+/// let v = cascade!(Vec::new() => .push(3); .push(1); .sort());
+/// assert_eq!(v, [1, 3]);
+/// ```
+#[macro_export]
+macro_rules! cascade {
+    ($value:expr => $(. $method:ident ($($args:tt)*));+ $(;)?) => {{
+        let mut value = $value;
+        $(
+            value.$method($($args)*);
+        )+
+        value
+    }};
+}
+
+/// A closure, or a block, which binds its value to a name
+///
+/// `scope!(v { body })` is `|v| { body }`, for stages with several statements.
+/// `scope!(value => v { body })` binds `value` directly and runs the block. Either name
+/// may be preceded by `mut`.
+///
+/// ```
+/// use ov::*;
+///
+/// let total = vec![3, 1, 2].over(scope!(mut v {
+///     v.sort();
+///     v.dedup();
+///     v.iter().sum::<i32>()
+/// }));
+/// assert_eq!(total, 6);
+///
+/// let label = scope!(total => n {
+///     let tens = n / 10;
+///     format!("{} tens, {} ones", tens, n % 10)
+/// });
+/// assert_eq!(label, "0 tens, 6 ones");
+/// ```
+#[macro_export]
+macro_rules! scope {
+    ($name:ident $body:block) => {
+        |$name| $body
+    };
+    (mut $name:ident $body:block) => {
+        |mut $name| $body
+    };
+    ($value:expr => $name:ident $body:block) => {{
+        let $name = $value;
+        $body
+    }};
+    ($value:expr => mut $name:ident $body:block) => {{
+        let mut $name = $value;
+        $body
+    }};
+}
+
+/// A closure which applies `f` when a cfg predicate holds, and is the identity otherwise
+///
+/// The predicate is evaluated in the calling crate, so `feature = "..."` refers to its
+/// features. When the predicate is false `f` isn't compiled, so it may use items which only
+/// exist under the predicate.
+///
+/// ```
+/// use ov::*;
+///
+/// let path = String::from("dir/file").over(over_cfg!(windows, |p: String| p.replace('/', "\\")));
+/// # #[cfg(not(windows))]
+/// assert_eq!(path, "dir/file");
+///
+/// let n = 2.over(over_cfg!(all(), |n| n * 10));
+/// assert_eq!(n, 20);
+/// ```
+#[macro_export]
+macro_rules! over_cfg {
+    ($pred:meta, $f:expr $(,)?) => {
+        |value| {
+            #[cfg($pred)]
+            let value = ($f)(value);
+            value
+        }
+    };
+}
+
+/// Pass unique references to several fields of a value to a closure
+///
+/// `over_fields!(value => a, b => f)` is `f(&mut value.a, &mut value.b)`. The fields are
+/// borrowed separately, so they can be mutated together where a closure taking the whole
+/// value couldn't reach them through helper methods. Tuple fields are written by index.
+/// Postfix macros don't exist, so this can't be written as `value.over_fields!(..)`.
+///
+/// A closure written in place is expanded inline, like the stages of
+/// [`try_pipe!`](macro.try_pipe.html), so its arguments get the fields' types and `?` applies
+/// to the enclosing function. Any other callable is called with the references.
+///
+/// ```
+/// use ov::*;
+///
+/// struct Game {
+///     players: Vec<&'static str>,
+///     log: Vec<String>,
+///     turn: usize,
+/// }
+///
+/// let mut game = Game { players: vec!["a", "b"], log: Vec::new(), turn: 0 };
+///
+/// for _ in 0..3 {
+///     over_fields!(game => players, log, turn => |players, log, turn| {
+///         log.push(format!("{} plays", players[*turn % players.len()]));
+///         *turn += 1;
+///     });
+/// }
+/// assert_eq!(game.log, ["a plays", "b plays", "a plays"]);
+///
+/// let mut pair = (vec![1, 2], 0);
+/// let total = over_fields!(pair => 0, 1 => |items, sum| {
+///     items.push(3);
+///     *sum = items.iter().sum();
+///     *sum
+/// });
+/// assert_eq!((total, pair.1), (6, 6));
+///
+/// fn swap_ends(a: &mut Vec<i32>, b: &mut i32) {
+///     std::mem::swap(&mut a[0], b);
+/// }
+///
+/// over_fields!(pair => 0, 1 => swap_ends);
+/// assert_eq!(pair, (vec![6, 2, 3], 1));
+/// ```
+#[macro_export]
+macro_rules! over_fields {
+    ($value:expr => $($field:tt),+ => |$($arg:pat_param),+| $body:expr) => {{
+        let value = &mut $value;
+        $(
+            let $arg = &mut value.$field;
+        )+
+        $body
+    }};
+    ($value:expr => $($field:tt),+ => $f:expr) => {{
+        let value = &mut $value;
+        ($f)($(&mut value.$field),+)
+    }};
+}
@@ -0,0 +1,157 @@
+//! Stages which rebuild a map with transformed keys or values.
+
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
+
+/// Rebuild a map with each value transformed
+/// (`self |> self.into_iter().map(|(k, v)| (k, f(v))).collect()`)
+///
+/// Implemented for `BTreeMap` and, with `std`, `HashMap`, which keeps its hasher.
+///
+/// ```
+/// use ov::*;
+/// use std::collections::BTreeMap;
+///
+/// let prices = BTreeMap::from([("fig", 120), ("kiwi", 45)]);
+/// let dollars = prices.over_map_values(|cents| cents as f64 / 100.0);
+/// assert_eq!(dollars, BTreeMap::from([("fig", 1.2), ("kiwi", 0.45)]));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a map whose values can be transformed",
+        label = "not a `BTreeMap` or `HashMap`",
+        note = "`over_map_values` is implemented for `BTreeMap<K, V>` and `HashMap<K, V, S>`"
+    )
+)]
+pub trait OverMapValues<V, W> {
+    type Output;
+
+    fn over_map_values<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(V) -> W;
+}
+
+impl<K: Ord, V, W> OverMapValues<V, W> for BTreeMap<K, V> {
+    type Output = BTreeMap<K, W>;
+
+    fn over_map_values<F>(self, mut f: F) -> BTreeMap<K, W>
+    where
+        F: FnMut(V) -> W,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, W, S> OverMapValues<V, W> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Output = HashMap<K, W, S>;
+
+    fn over_map_values<F>(self, mut f: F) -> HashMap<K, W, S>
+    where
+        F: FnMut(V) -> W,
+    {
+        let mut out = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        out.extend(self.into_iter().map(|(k, v)| (k, f(v))));
+        out
+    }
+}
+
+/// Rebuild a map with each key transformed, combining the values of keys which collide
+/// (`self |> self.into_iter().map(|(k, v)| (key(k), v)).collect()`)
+///
+/// When two keys map to the same new key, `merge(existing, incoming)` gives the value to
+/// keep. A `BTreeMap` is visited in ascending order of its old keys, so `|a, _| a` keeps
+/// the value of the smallest one; a `HashMap` is visited in no particular order, so its
+/// `merge` should not depend on the order of its arguments.
+///
+/// Implemented for `BTreeMap` and, with `std`, `HashMap`, which keeps its hasher.
+///
+/// ```
+/// use ov::*;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let files = BTreeMap::from([("a.RS", 3), ("b.rs", 5), ("c.md", 1)]);
+/// let by_ext = files.over_map_keys(
+///     |name| name.rsplit('.').next().unwrap().to_lowercase(),
+///     |total, lines| total + lines,
+/// );
+/// assert_eq!(by_ext, BTreeMap::from([("md".to_owned(), 1), ("rs".to_owned(), 8)]));
+///
+/// let first = BTreeMap::from([(10, 'a'), (11, 'b'), (20, 'c')]);
+/// assert_eq!(first.over_map_keys(|k| k / 10, |a, _| a), BTreeMap::from([(1, 'a'), (2, 'c')]));
+///
+/// let counts = HashMap::from([("x", 1), ("X", 2)]);
+/// let folded = counts.over_map_keys(|k| k.to_ascii_lowercase(), |a, b| a + b);
+/// assert_eq!(folded, HashMap::from([("x".to_owned(), 3)]));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a map whose keys can be transformed into `{J}`",
+        label = "not a `BTreeMap` or `HashMap`, or `{J}` can't be its key",
+        note = "`over_map_keys` is implemented for `BTreeMap<K, V>` with `Ord` keys and `HashMap<K, V, S>` with `Eq + Hash` keys"
+    )
+)]
+pub trait OverMapKeys<K, V, J> {
+    type Output;
+
+    fn over_map_keys<F, G>(self, key: F, merge: G) -> Self::Output
+    where
+        F: FnMut(K) -> J,
+        G: FnMut(V, V) -> V;
+}
+
+impl<K, V, J: Ord> OverMapKeys<K, V, J> for BTreeMap<K, V> {
+    type Output = BTreeMap<J, V>;
+
+    fn over_map_keys<F, G>(self, mut key: F, mut merge: G) -> BTreeMap<J, V>
+    where
+        F: FnMut(K) -> J,
+        G: FnMut(V, V) -> V,
+    {
+        let mut out = BTreeMap::new();
+        for (k, v) in self {
+            let j = key(k);
+            let v = match out.remove(&j) {
+                Some(existing) => merge(existing, v),
+                None => v,
+            };
+            out.insert(j, v);
+        }
+        out
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, J, S> OverMapKeys<K, V, J> for HashMap<K, V, S>
+where
+    J: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Output = HashMap<J, V, S>;
+
+    fn over_map_keys<F, G>(self, mut key: F, mut merge: G) -> HashMap<J, V, S>
+    where
+        F: FnMut(K) -> J,
+        G: FnMut(V, V) -> V,
+    {
+        let mut out = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        for (k, v) in self {
+            let j = key(k);
+            let v = match out.remove(&j) {
+                Some(existing) => merge(existing, v),
+                None => v,
+            };
+            out.insert(j, v);
+        }
+        out
+    }
+}
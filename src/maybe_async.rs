@@ -0,0 +1,242 @@
+//! Generation of the sync traits together with their async counterparts.
+//!
+//! Each entry names a sync trait, the async trait for futures, and the receiver mode. The
+//! async trait awaits the future, then passes its output to the callback in the same way
+//! that the sync trait passes `self`. Adding an entry here keeps both surfaces in lockstep.
+
+macro_rules! maybe_async {
+    ($(
+        $(#[$sync_attr:meta])*
+        $sync:ident::$sync_method:ident,
+        $(#[$async_attr:meta])*
+        $async_:ident::$async_method:ident => $mode:ident;
+    )*) => {
+        $(
+            maybe_async!(
+                @$mode
+                [$(#[$sync_attr])*] $sync $sync_method
+                [$(#[$async_attr])*] $async_ $async_method
+            );
+        )*
+    };
+
+    (@value [$($sync_attr:tt)*] $sync:ident $sync_method:ident
+        [$($async_attr:tt)*] $async_:ident $async_method:ident) => {
+        $($sync_attr)*
+        pub trait $sync: Sized {
+            fn $sync_method<F, Ret>(self, f: F) -> Ret
+            where
+                F: FnOnce(Self) -> Ret,
+            {
+                f(self)
+            }
+        }
+
+        impl<T> $sync for T {}
+
+        #[cfg(feature = "async")]
+        $($async_attr)*
+        pub trait $async_: ::core::future::Future + Sized {
+            fn $async_method<F, Ret>(self, f: F) -> impl ::core::future::Future<Output = Ret>
+            where
+                F: FnOnce(Self::Output) -> Ret,
+            {
+                async move { f(self.await) }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<T> $async_ for T where T: ::core::future::Future {}
+    };
+
+    (@ref [$($sync_attr:tt)*] $sync:ident $sync_method:ident
+        [$($async_attr:tt)*] $async_:ident $async_method:ident) => {
+        $($sync_attr)*
+        pub trait $sync {
+            fn $sync_method<F, Ret>(&self, f: F) -> Ret
+            where
+                F: FnOnce(&Self) -> Ret,
+            {
+                f(self)
+            }
+        }
+
+        impl<T> $sync for T {}
+
+        #[cfg(feature = "async")]
+        $($async_attr)*
+        pub trait $async_: ::core::future::Future + Sized {
+            fn $async_method<F, Ret>(self, f: F) -> impl ::core::future::Future<Output = Ret>
+            where
+                F: FnOnce(&Self::Output) -> Ret,
+            {
+                async move { f(&self.await) }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<T> $async_ for T where T: ::core::future::Future {}
+    };
+
+    (@mut [$($sync_attr:tt)*] $sync:ident $sync_method:ident
+        [$($async_attr:tt)*] $async_:ident $async_method:ident) => {
+        $($sync_attr)*
+        pub trait $sync {
+            fn $sync_method<F, Ret>(&mut self, f: F) -> Ret
+            where
+                F: FnOnce(&mut Self) -> Ret,
+            {
+                f(self)
+            }
+        }
+
+        impl<T> $sync for T {}
+
+        #[cfg(feature = "async")]
+        $($async_attr)*
+        pub trait $async_: ::core::future::Future + Sized {
+            fn $async_method<F, Ret>(self, f: F) -> impl ::core::future::Future<Output = Ret>
+            where
+                F: FnOnce(&mut Self::Output) -> Ret,
+            {
+                async move { f(&mut self.await) }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<T> $async_ for T where T: ::core::future::Future {}
+    };
+
+    (@deref [$($sync_attr:tt)*] $sync:ident $sync_method:ident
+        [$($async_attr:tt)*] $async_:ident $async_method:ident) => {
+        $($sync_attr)*
+        pub trait $sync: ::core::ops::Deref {
+            fn $sync_method<F, Ret>(&self, f: F) -> Ret
+            where
+                F: FnOnce(&<Self as ::core::ops::Deref>::Target) -> Ret;
+        }
+
+        impl<T> $sync for T
+        where
+            T: ::core::ops::Deref,
+        {
+            fn $sync_method<F, Ret>(&self, f: F) -> Ret
+            where
+                F: FnOnce(&Self::Target) -> Ret,
+            {
+                f(::core::ops::Deref::deref(self))
+            }
+        }
+
+        #[cfg(feature = "async")]
+        $($async_attr)*
+        pub trait $async_: ::core::future::Future + Sized
+        where
+            Self::Output: ::core::ops::Deref,
+        {
+            fn $async_method<F, Ret>(self, f: F) -> impl ::core::future::Future<Output = Ret>
+            where
+                F: FnOnce(&<Self::Output as ::core::ops::Deref>::Target) -> Ret,
+            {
+                async move { f(::core::ops::Deref::deref(&self.await)) }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<T> $async_ for T
+        where
+            T: ::core::future::Future,
+            T::Output: ::core::ops::Deref,
+        {
+        }
+    };
+
+    (@deref_mut [$($sync_attr:tt)*] $sync:ident $sync_method:ident
+        [$($async_attr:tt)*] $async_:ident $async_method:ident) => {
+        $($sync_attr)*
+        pub trait $sync: ::core::ops::DerefMut {
+            fn $sync_method<F, Ret>(&mut self, f: F) -> Ret
+            where
+                F: FnOnce(&mut Self::Target) -> Ret;
+        }
+
+        impl<T, DerefTarget> $sync for T
+        where
+            T: ::core::ops::DerefMut,
+            T: ::core::ops::Deref<Target = DerefTarget>,
+        {
+            fn $sync_method<F, Ret>(&mut self, f: F) -> Ret
+            where
+                F: FnOnce(&mut DerefTarget) -> Ret,
+            {
+                f(::core::ops::DerefMut::deref_mut(self))
+            }
+        }
+
+        #[cfg(feature = "async")]
+        $($async_attr)*
+        pub trait $async_: ::core::future::Future + Sized
+        where
+            Self::Output: ::core::ops::DerefMut,
+        {
+            fn $async_method<F, Ret>(self, f: F) -> impl ::core::future::Future<Output = Ret>
+            where
+                F: FnOnce(&mut <Self::Output as ::core::ops::Deref>::Target) -> Ret,
+            {
+                async move { f(::core::ops::DerefMut::deref_mut(&mut self.await)) }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<T> $async_ for T
+        where
+            T: ::core::future::Future,
+            T::Output: ::core::ops::DerefMut,
+        {
+        }
+    };
+}
+
+// The async counterparts of combinators which take `self` by value. These are written next to
+// the sync trait, repeating its method signature, and the generated method awaits the future
+// and passes the output to the sync method, so the behavior can't drift between the two.
+//
+// Stages which borrow the receiver, such as `over_retry` or `over_try_each`, don't get
+// counterparts: the awaited output only lives inside the returned future, so there's nothing
+// left to borrow once it completes. Await those first and call the sync stage.
+macro_rules! async_twin {
+    ($(
+        $(#[$attr:meta])*
+        $async_:ident<$($tp:ident),*> for $sync:ident::$sync_method:ident => $async_method:ident
+            <$($gen:ident),*>($($arg:ident: $argty:ty),*) -> $ret:ty
+            $(where { $($bound:tt)* })?;
+    )*) => {
+        $(
+            #[cfg(feature = "async")]
+            $(#[$attr])*
+            pub trait $async_<$($tp),*>: ::core::future::Future + Sized
+            where
+                Self::Output: $sync<$($tp),*>,
+            {
+                fn $async_method<$($gen),*>(
+                    self,
+                    $($arg: $argty),*
+                ) -> impl ::core::future::Future<Output = $ret>
+                $(where $($bound)*)?
+                {
+                    async move {
+                        <Self::Output as $sync<$($tp),*>>::$sync_method(self.await, $($arg),*)
+                    }
+                }
+            }
+
+            #[cfg(feature = "async")]
+            impl<Fut, $($tp),*> $async_<$($tp),*> for Fut
+            where
+                Fut: ::core::future::Future,
+                Fut::Output: $sync<$($tp),*>,
+            {
+            }
+        )*
+    };
+}
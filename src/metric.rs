@@ -0,0 +1,42 @@
+//! Stages which emit metrics through the `metrics` facade.
+
+use std::time::Instant;
+
+/// Increment a counter, returning the receiver (`metrics::counter!(name) += 1 |> self`)
+///
+/// Like every `metrics` call, this does nothing until a recorder is installed.
+///
+/// ```
+/// use ov::*;
+///
+/// let parsed = "42"
+///     .tap_metric_counter("requests")
+///     .parse::<u32>()
+///     .over_metric_timer("double", |n| n.map(|n| n * 2));
+/// assert_eq!(parsed, Ok(84));
+/// ```
+pub trait TapMetricCounter: Sized {
+    fn tap_metric_counter(self, name: &'static str) -> Self {
+        metrics::counter!(name).increment(1);
+        self
+    }
+}
+
+impl<T> TapMetricCounter for T {}
+
+/// Transform by value, recording how long the closure took in a histogram (`self |> f`)
+///
+/// The duration is recorded in seconds.
+pub trait OverMetricTimer: Sized {
+    fn over_metric_timer<F, Ret>(self, name: &'static str, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        let start = Instant::now();
+        let ret = f(self);
+        metrics::histogram!(name).record(start.elapsed());
+        ret
+    }
+}
+
+impl<T> OverMetricTimer for T {}
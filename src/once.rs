@@ -0,0 +1,141 @@
+//! Stages for cells which are initialized once, and side effects which run once.
+
+use core::cell::OnceCell;
+#[cfg(feature = "sync")]
+use std::sync::OnceLock;
+
+/// Transform the contents of a once cell, initializing it first if needed
+/// (`&self |> get_or_init(init)`)
+///
+/// Implemented for `OnceCell` and, with `sync`, `OnceLock`. `LazyCell` and `LazyLock`
+/// implement `Deref`, so [`over_deref`](trait.OverDeref.html) already forces them.
+///
+/// ```
+/// use ov::*;
+/// use std::sync::OnceLock;
+///
+/// # #[cfg(feature = "sync")] {
+/// static CONFIG: OnceLock<Vec<String>> = OnceLock::new();
+///
+/// let load = || vec!["verbose".to_string()];
+/// let verbose = CONFIG.over_get_or_init(load, |flags| flags.iter().any(|f| f == "verbose"));
+/// assert!(verbose);
+/// # }
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_get_or_init` is only implemented for once cells, not `{Self}`",
+        label = "not a once cell",
+        note = "`over_get_or_init` is implemented for `OnceCell<T>` and `OnceLock<T>`; use `over_deref` for `LazyCell` and `LazyLock`"
+    )
+)]
+pub trait OverGetOrInit<T> {
+    fn over_get_or_init<I, F, Ret>(&self, init: I, f: F) -> Ret
+    where
+        I: FnOnce() -> T,
+        F: FnOnce(&T) -> Ret;
+}
+
+impl<T> OverGetOrInit<T> for OnceCell<T> {
+    fn over_get_or_init<I, F, Ret>(&self, init: I, f: F) -> Ret
+    where
+        I: FnOnce() -> T,
+        F: FnOnce(&T) -> Ret,
+    {
+        f(self.get_or_init(init))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T> OverGetOrInit<T> for OnceLock<T> {
+    fn over_get_or_init<I, F, Ret>(&self, init: I, f: F) -> Ret
+    where
+        I: FnOnce() -> T,
+        F: FnOnce(&T) -> Ret,
+    {
+        f(self.get_or_init(init))
+    }
+}
+
+/// Initialize a once cell if it's empty, returning the cell (`self.get_or_init(init) |> self`)
+///
+/// ```
+/// use ov::*;
+/// use std::cell::OnceCell;
+///
+/// let cell = OnceCell::new().tap_init(|| 5);
+/// assert_eq!(cell.get(), Some(&5));
+///
+/// let cell = cell.tap_init(|| 6);
+/// assert_eq!(cell.get(), Some(&5));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`tap_init` is only implemented for once cells, not `{Self}`",
+        label = "not a once cell",
+        note = "`tap_init` is implemented for `OnceCell<T>` and `OnceLock<T>`"
+    )
+)]
+pub trait TapInit<T>: Sized {
+    fn tap_init<I>(self, init: I) -> Self
+    where
+        I: FnOnce() -> T;
+}
+
+impl<T> TapInit<T> for OnceCell<T> {
+    fn tap_init<I>(self, init: I) -> Self
+    where
+        I: FnOnce() -> T,
+    {
+        self.get_or_init(init);
+        self
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T> TapInit<T> for OnceLock<T> {
+    fn tap_init<I>(self, init: I) -> Self
+    where
+        I: FnOnce() -> T,
+    {
+        self.get_or_init(init);
+        self
+    }
+}
+
+/// A closure which inspects the value the first time this call site runs, then returns it
+///
+/// `tap_once!(f)` is `|value| { ONCE.call_once(|| f(&value)); value }`, with a
+/// `static ONCE: Once` for each place the macro is written. So a `tap_once!` inside a loop
+/// or a frequently called function runs `f` once per process, and every later call is a
+/// single atomic load.
+///
+/// ```
+/// use ov::*;
+///
+/// fn parse_legacy(input: &str) -> usize {
+///     input
+///         .over(tap_once!(|s| eprintln!("parse_legacy is deprecated, called with {:?}", s)))
+///         .len()
+/// }
+///
+/// let mut warned = 0;
+/// for input in ["a", "bb", "ccc"] {
+///     input.over(tap_once!(|_| warned += 1));
+///     parse_legacy(input);
+/// }
+/// assert_eq!(warned, 1);
+/// ```
+#[cfg(all(feature = "sync", feature = "macros"))]
+#[macro_export]
+macro_rules! tap_once {
+    ($f:expr $(,)?) => {
+        |value| {
+            static ONCE: $crate::__Once = $crate::__Once::new();
+            ONCE.call_once(|| ($f)(&value));
+            value
+        }
+    };
+}
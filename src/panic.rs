@@ -0,0 +1,62 @@
+//! Catching panics inside a stage.
+
+use std::any::Any;
+use std::panic::{self, UnwindSafe};
+
+/// Transform by value, catching a panic as its message
+/// (`catch_unwind(|| f(self)).map_err(message)`)
+///
+/// Payloads of `&str` and `String`, which `panic!` produces, become the message. Other
+/// payloads become `"Box<dyn Any>"`, unless
+/// [`over_catch_msg_with`](#method.over_catch_msg_with) is given a mapping for them. The
+/// panic hook still runs, so the message is printed as usual.
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(2.over_catch_msg(|n| n * 3), Ok(6));
+///
+/// let err = 2.over_catch_msg(|n| -> i32 { panic!("bad input: {}", n) });
+/// assert_eq!(err, Err("bad input: 2".to_string()));
+///
+/// struct Code(u16);
+///
+/// let err = 2.over_catch_msg_with(
+///     |_| -> i32 { std::panic::panic_any(Code(404)) },
+///     |payload| payload.downcast_ref::<Code>().map(|c| format!("code {}", c.0)),
+/// );
+/// assert_eq!(err, Err("code 404".to_string()));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` may not be unwind safe",
+        label = "not `UnwindSafe`",
+        note = "wrap the value in `std::panic::AssertUnwindSafe` if a panic can't leave it in a broken state"
+    )
+)]
+pub trait OverCatchMsg: Sized + UnwindSafe {
+    fn over_catch_msg<F, Ret>(self, f: F) -> Result<Ret, String>
+    where
+        F: FnOnce(Self) -> Ret + UnwindSafe,
+    {
+        self.over_catch_msg_with(f, |_| None)
+    }
+
+    /// Like [`over_catch_msg`](#method.over_catch_msg), trying `map` on the payload before
+    /// the `&str` and `String` downcasts
+    fn over_catch_msg_with<F, M, Ret>(self, f: F, map: M) -> Result<Ret, String>
+    where
+        F: FnOnce(Self) -> Ret + UnwindSafe,
+        M: FnOnce(&(dyn Any + Send)) -> Option<String>,
+    {
+        panic::catch_unwind(move || f(self)).map_err(|payload| {
+            map(&*payload)
+                .or_else(|| payload.downcast_ref::<&str>().map(|s| (*s).to_owned()))
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".to_owned())
+        })
+    }
+}
+
+impl<T> OverCatchMsg for T where T: UnwindSafe {}
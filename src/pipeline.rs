@@ -0,0 +1,79 @@
+//! A plain wrapper for a value going through a pipeline.
+
+use crate::state::WithState;
+
+/// A value going through a pipeline, without any context or state
+///
+/// [`over`](#method.over) runs a stage on the value, like [`Over::over`](trait.Over.html),
+/// but keeps the result wrapped so that stages are written the same way as with
+/// [`WithState`](struct.WithState.html) and [`WithEnv`](struct.WithEnv.html). A pipeline can
+/// pick up state part way through with [`with_state`](#method.with_state), and converting a
+/// `WithState` back into an `Ov` drops the state.
+///
+/// ```
+/// use ov::*;
+///
+/// let (len, calls) = Ov::new("  hi  ")
+///     .over(str::trim)
+///     .with_state(0)
+///     .over(|s, calls| {
+///         *calls += 1;
+///         s.len()
+///     })
+///     .into_parts();
+/// assert_eq!((len, calls), (2, 1));
+///
+/// let plain: Ov<usize> = 5.over_state("unused").over(|n, _| n + 1).into();
+/// assert_eq!(plain.into_value(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ov<T> {
+    value: T,
+}
+
+impl<T> Ov<T> {
+    pub fn new(value: T) -> Self {
+        Ov { value }
+    }
+
+    /// Run a stage, passing it the value
+    pub fn over<F, U>(self, f: F) -> Ov<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Ov::new(f(self.value))
+    }
+
+    /// Continue the pipeline with a state
+    pub fn with_state<S>(self, state: S) -> WithState<S, T> {
+        WithState::new(self.value, state)
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Finish the pipeline
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for Ov<T> {
+    fn from(value: T) -> Self {
+        Ov::new(value)
+    }
+}
+
+impl<S, T> From<WithState<S, T>> for Ov<T> {
+    fn from(with_state: WithState<S, T>) -> Self {
+        Ov::new(with_state.into_value())
+    }
+}
+
+/// Starts with the default state
+impl<S: Default, T> From<Ov<T>> for WithState<S, T> {
+    fn from(ov: Ov<T>) -> Self {
+        ov.with_state(S::default())
+    }
+}
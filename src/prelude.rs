@@ -0,0 +1,119 @@
+//! Every enabled trait and macro, for wildcard importing.
+//!
+//! This glob-imports each enabled subset of [`preludes`](../preludes/index.html), along with
+//! the traits and macros of the optional integrations.
+//!
+//! ```
+//! use ov::prelude::*;
+//!
+//! assert_eq!(2.over(|n| n * 3), 6);
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::preludes::alloc::*;
+pub use crate::preludes::core::*;
+#[cfg(feature = "io")]
+pub use crate::preludes::io::*;
+#[cfg(feature = "macros")]
+pub use crate::preludes::macros::*;
+#[cfg(feature = "std")]
+pub use crate::preludes::std::*;
+#[cfg(feature = "sync")]
+pub use crate::preludes::sync::*;
+
+#[cfg(feature = "aliases")]
+pub use crate::aliases::{Apply, Pipe, PipeDeref, PipeDerefMut, PipeMut, PipeRef, With};
+
+#[cfg(feature = "kotlin")]
+pub use crate::kotlin::ScopeFunctions;
+
+#[cfg(feature = "async")]
+pub use crate::{OverAwait, OverDerefAwait, OverDerefMutAwait, OverMutAwait, OverRefAwait};
+#[cfg(feature = "async")]
+pub use crate::{
+    OverFirstAwait, OverFlattenAwait, OverInsideAwait, OverOrElseWithAwait, OverRecoverAwait,
+    OverSecondAwait, OverValidateAwait,
+};
+
+#[cfg(feature = "nightly")]
+pub use crate::question::OverQ;
+
+#[cfg(feature = "rand")]
+pub use crate::random::{OverRng, OverWithRng};
+
+#[cfg(feature = "defmt")]
+pub use crate::defmt_log::TapDefmt;
+
+#[cfg(feature = "critical-section")]
+pub use crate::critical::OverCritical;
+
+#[cfg(all(feature = "crossbeam", target_has_atomic = "ptr"))]
+pub use crate::atomic_cell::OverAtomicCell;
+
+#[cfg(feature = "audit")]
+pub use crate::audit::OverAudited;
+#[cfg(feature = "record")]
+pub use crate::record::OverRecorded;
+
+#[cfg(feature = "either")]
+pub use crate::branch::OverBranch;
+
+#[cfg(feature = "count-allocations")]
+pub use crate::alloc_count::{OverAllocBudget, OverAllocCount};
+
+#[cfg(feature = "metrics")]
+pub use crate::metric::{OverMetricTimer, TapMetricCounter};
+
+#[cfg(feature = "hooks")]
+pub use crate::hooks::OverHooked;
+
+#[cfg(feature = "rayon")]
+pub use crate::fork::OverParFork;
+#[cfg(feature = "rayon")]
+pub use crate::iter::OverParEach;
+#[cfg(feature = "rayon")]
+pub use crate::slice::{OverParChunks, OverParWindows};
+
+#[cfg(feature = "unicode")]
+pub use crate::text::{OverGraphemes, OverWords};
+
+#[cfg(feature = "test-helpers")]
+pub use crate::roundtrip::AssertRoundtrip;
+
+#[cfg(feature = "serde_json")]
+pub use crate::json::{OverPointer, OverPointerMut};
+
+#[cfg(all(feature = "insta", feature = "macros"))]
+pub use crate::tap_snapshot;
+
+#[cfg(feature = "proptest")]
+pub use crate::property::OverPropAssert;
+
+#[cfg(feature = "bumpalo")]
+pub use crate::arena::{OverAllocIn, OverAllocSliceIn};
+
+#[cfg(all(feature = "profiling", feature = "macros"))]
+pub use crate::over_profile_scope;
+
+#[cfg(feature = "indicatif")]
+pub use crate::progress::{OverEachProgress, OverWithProgress};
+
+#[cfg(feature = "yaml")]
+pub use crate::format::{OverFromYaml, OverToYaml};
+
+#[cfg(feature = "toml")]
+pub use crate::format::{OverFromToml, OverToToml};
+
+#[cfg(feature = "regex")]
+pub use crate::text::{OverCaptures, OverCapturesIter};
+
+#[cfg(feature = "frunk")]
+pub use crate::generic::{OverGeneric, OverHList};
+
+#[cfg(feature = "futures")]
+pub use crate::concurrent::{
+    OverBufferUnordered, OverBuffered, OverForEachConcurrent, OverJoin, OverJoinRef, OverRace,
+};
+
+#[cfg(all(feature = "pin-project", feature = "macros"))]
+pub use crate::over_project;
@@ -0,0 +1,97 @@
+//! The traits and macros of each feature tier, for wildcard importing only some of them.
+//!
+//! [`prelude`](../prelude/index.html) re-exports every enabled subset. Importing a subset
+//! instead keeps the methods of the other tiers out of scope, such as the `std` ones in a
+//! crate which also has `no_std` builds, or the printing stages of `io` in library code.
+//!
+//! ```
+//! use ov::preludes::core::*;
+//!
+//! assert_eq!(Some(2).over_inside(|n| n * 3), Some(6));
+//! ```
+
+/// The traits which only need `core`
+pub mod core {
+    pub use crate::{Over, OverDeref, OverDerefMut, OverMut, OverRef};
+
+    pub use crate::breakpoint::TapBreakpoint;
+    pub use crate::budget::OverTimeBudget;
+    pub use crate::build::BuildWith;
+    pub use crate::call::{OverCall, OverCallMut};
+    #[cfg(target_has_atomic = "64")]
+    pub use crate::counter::{Increment, OverCounted};
+    pub use crate::downcast::OverDowncast;
+    pub use crate::dynamic::OverDyn;
+    pub use crate::env::OverEnv;
+    pub use crate::flatten::OverFlatten;
+    pub use crate::fmt_with::{DebugWith, DisplayWith};
+    pub use crate::fork::OverFork;
+    pub use crate::functor::OverFunctor;
+    pub use crate::in_place::OverInPlace;
+    pub use crate::iter::{
+        IterWith, OverEachMut, OverFold, OverIterate, OverScan, OverTryEach, OverUnfold,
+    };
+    pub use crate::key::{IntoEqBy, IntoHashBy, IntoOrdBy};
+    pub use crate::once::{OverGetOrInit, TapInit};
+    pub use crate::profile::{OverDebugOnly, OverReleaseOnly};
+    pub use crate::result::{OverOrElseWith, OverRecover};
+    pub use crate::retry::OverRetry;
+    pub use crate::select::{OverMaxBy, OverMaxWith, OverMinBy, OverMinWith};
+    pub use crate::slice::{OverChunks, OverWindows};
+    pub use crate::state::OverState;
+    pub use crate::text::OverLines;
+    pub use crate::transaction::OverMutTransactional;
+    pub use crate::tuple::{OverFirst, OverSecond};
+    pub use crate::types::ExpectType;
+    pub use crate::uninit::{OverInit, OverInitUnchecked, TryOverInit};
+    pub use crate::validate::OverValidate;
+}
+
+/// The traits which allocate
+#[cfg(feature = "alloc")]
+pub mod alloc {
+    pub use crate::boxed::OverMapBox;
+    pub use crate::call::OverBoxed;
+    pub use crate::diff::OverMutDiff;
+    pub use crate::extract::OverExtractIf;
+    pub use crate::finish::{OverLeak, OverLeakRef, PinBoxed, Shared, WeakShared};
+    pub use crate::logged::OverLogged;
+    pub use crate::map::{OverMapKeys, OverMapValues};
+    pub use crate::string::{OverPushStr, OverReplaced, OverTrimmed};
+    pub use crate::vec::{
+        OverDeduped, OverDedupedByKey, OverReversed, OverSorted, OverSortedByKey,
+    };
+    pub use crate::weak::{OverDowngrade, OverUpgradeWith};
+}
+
+/// The traits which need the standard library, other than those of `sync` and `io`
+#[cfg(feature = "std")]
+pub mod std {
+    pub use crate::panic::OverCatchMsg;
+    pub use crate::spy::TapSpy;
+}
+
+/// The traits and macros which share values between threads
+#[cfg(feature = "sync")]
+pub mod sync {
+    pub use crate::finish::{RwLocked, Synchronized};
+    #[cfg(feature = "macros")]
+    pub use crate::tap_once;
+    pub use crate::thread::OverScoped;
+}
+
+/// The traits which print to stderr
+#[cfg(feature = "io")]
+pub mod io {
+    pub use crate::pretty::TapPretty;
+    pub use crate::types::{TapLayout, TapLayoutVal, TapTypeName};
+}
+
+/// The general-purpose macros of the `macros` feature
+#[cfg(feature = "macros")]
+pub mod macros {
+    pub use crate::{
+        cascade, chain, def_over, impl_over, over_cfg, over_fields, over_let, over_match, scope,
+        thread_first, thread_last, try_pipe,
+    };
+}
@@ -0,0 +1,86 @@
+//! Printing the value in a chain with its pretty `Debug` form.
+
+use std::fmt::Debug;
+use std::io::{self, Write};
+use std::panic::Location;
+
+fn write_pretty<T, W>(value: &T, label: Option<&str>, caller: &Location, mut out: W)
+where
+    T: Debug + ?Sized,
+    W: Write,
+{
+    match label {
+        Some(label) => writeln!(out, "[{}] {} = {:#?}", caller, label, value),
+        None => writeln!(out, "[{}] {:#?}", caller, value),
+    }
+    .expect("tap_pretty: failed to write the value");
+}
+
+/// Print the pretty `Debug` form of the value to stderr, returning the receiver
+/// (`eprintln!("{:#?}", self) |> self`)
+///
+/// The output starts with the caller's location, and the label if one is given, in the
+/// style of `dbg!`. Unlike `dbg!`, the value isn't moved into a macro, and the pretty form is
+/// used even for short values.
+///
+/// ```
+/// use ov::*;
+///
+/// #[derive(Debug)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// // Prints something like:
+/// // [src/main.rs:9:6] moved = Point {
+/// //     x: 2,
+/// //     y: 3,
+/// // }
+/// let p = Point { x: 1, y: 3 }
+///     .over(|p| Point { x: p.x + 1, ..p })
+///     .tap_pretty_labeled("moved");
+/// assert_eq!(p.x, 2);
+/// ```
+///
+/// [`tap_pretty_to`](#method.tap_pretty_to) writes somewhere else, such as a buffer in tests:
+///
+/// ```
+/// use ov::*;
+///
+/// let mut out = Vec::new();
+/// let v = vec![1, 2].tap_pretty_to(None, &mut out);
+/// assert_eq!(v, [1, 2]);
+///
+/// let out = String::from_utf8(out).unwrap();
+/// assert!(out.starts_with("[src/"));
+/// assert!(out.ends_with("] [\n    1,\n    2,\n]\n"));
+/// ```
+pub trait TapPretty: Debug + Sized {
+    #[track_caller]
+    fn tap_pretty(self) -> Self {
+        write_pretty(&self, None, Location::caller(), io::stderr());
+        self
+    }
+
+    /// Like [`tap_pretty`](#method.tap_pretty), printing `label = ` before the value
+    #[track_caller]
+    fn tap_pretty_labeled(self, label: &str) -> Self {
+        write_pretty(&self, Some(label), Location::caller(), io::stderr());
+        self
+    }
+
+    /// Like [`tap_pretty`](#method.tap_pretty), writing to `out` instead of stderr
+    ///
+    /// Panics if writing fails, as printing to stderr does.
+    #[track_caller]
+    fn tap_pretty_to<W>(self, label: Option<&str>, out: W) -> Self
+    where
+        W: Write,
+    {
+        write_pretty(&self, label, Location::caller(), out);
+        self
+    }
+}
+
+impl<T> TapPretty for T where T: Debug {}
@@ -0,0 +1,73 @@
+//! Stages which only run in debug or in release builds.
+
+/// Transform by value in debug builds, and pass the value through in release builds
+/// (`self |> f` with `debug_assertions`, `self` otherwise)
+///
+/// The call to the closure is compiled out of release builds. Whether `debug_assertions` are
+/// enabled is decided when `ov` is compiled, which Cargo does with the same profile as the
+/// rest of the build.
+///
+/// ```
+/// use ov::*;
+///
+/// let sorted = vec![3, 1, 2]
+///     .over(|mut v| {
+///         v.sort();
+///         v
+///     })
+///     .over_debug_only(|v| {
+///         assert!(v.windows(2).all(|w| w[0] <= w[1]));
+///         v
+///     });
+/// assert_eq!(sorted, [1, 2, 3]);
+/// ```
+pub trait OverDebugOnly: Sized {
+    fn over_debug_only<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        #[cfg(debug_assertions)]
+        return f(self);
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = f;
+            self
+        }
+    }
+}
+
+impl<T> OverDebugOnly for T {}
+
+/// Transform by value in release builds, and pass the value through in debug builds
+/// (`self |> f` without `debug_assertions`, `self` otherwise)
+///
+/// The call to the closure is compiled out of debug builds.
+///
+/// ```
+/// use ov::*;
+///
+/// let mut ids = Vec::with_capacity(1024);
+/// ids.extend([3, 1, 2]);
+///
+/// let ids = ids.over_release_only(|mut v| {
+///     v.shrink_to_fit();
+///     v
+/// });
+/// assert_eq!(ids, [3, 1, 2]);
+/// ```
+pub trait OverReleaseOnly: Sized {
+    fn over_release_only<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        #[cfg(not(debug_assertions))]
+        return f(self);
+        #[cfg(debug_assertions)]
+        {
+            let _ = f;
+            self
+        }
+    }
+}
+
+impl<T> OverReleaseOnly for T {}
@@ -0,0 +1,80 @@
+//! Stages which report progress on an `indicatif` progress bar.
+
+use indicatif::ProgressBar;
+
+/// Transform by value, then advance the progress bar by one (`self |> f |> bar.inc(1)`)
+///
+/// The bar only advances if the closure returns, so a panicking stage isn't counted.
+///
+/// ```
+/// use indicatif::ProgressBar;
+/// use ov::*;
+///
+/// let bar = ProgressBar::hidden();
+/// bar.set_length(2);
+///
+/// let n = "21"
+///     .over_with_progress(&bar, |s| s.parse::<i32>().unwrap())
+///     .over_with_progress(&bar, |n| n * 2);
+/// assert_eq!(n, 42);
+/// assert_eq!(bar.position(), 2);
+/// ```
+pub trait OverWithProgress: Sized {
+    fn over_with_progress<F, Ret>(self, bar: &ProgressBar, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        let ret = f(self);
+        bar.inc(1);
+        ret
+    }
+}
+
+impl<T> OverWithProgress for T {}
+
+/// Run a closure on each item, advancing the progress bar after each one
+/// (`for item in self { f(item); bar.inc(1) }`)
+///
+/// If the bar has no length yet and the iterator knows its exact length, the bar's length
+/// is set to it first.
+///
+/// ```
+/// use indicatif::ProgressBar;
+/// use ov::*;
+///
+/// let bar = ProgressBar::hidden();
+/// let mut total = 0;
+///
+/// vec![1, 2, 3].over_each_progress(&bar, |n| total += n);
+/// assert_eq!(total, 6);
+/// assert_eq!((bar.position(), bar.length()), (3, Some(3)));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't iterable, so it can't be passed to `over_each_progress`",
+        label = "not `IntoIterator`",
+        note = "use `over_with_progress` to advance the bar once for the whole value"
+    )
+)]
+pub trait OverEachProgress: IntoIterator + Sized {
+    fn over_each_progress<F>(self, bar: &ProgressBar, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        let items = self.into_iter();
+        if bar.length().is_none() {
+            if let (len, Some(upper)) = items.size_hint() {
+                if len == upper {
+                    bar.set_length(len as u64);
+                }
+            }
+        }
+        items.for_each(|item| {
+            f(item);
+            bar.inc(1);
+        });
+    }
+}
+
+impl<I> OverEachProgress for I where I: IntoIterator {}
@@ -0,0 +1,57 @@
+//! Projecting pinned receivers to their fields.
+
+/// Project a pinned value to its fields and pass the projection on
+///
+/// `over_project!(pinned => f)` is `f(pinned.project())`, and
+/// `over_project!(ref pinned => f)` uses `project_ref` for a `Pin<&Self>`. The projection
+/// methods are the ones generated by `#[pin_project]`, so this also works with
+/// `pin-project-lite`, and the feature doesn't add either crate as a dependency. A closure written in place is expanded inline, like the stages of
+/// [`try_pipe!`](macro.try_pipe.html), so `return` and `?` apply to the enclosing `poll`.
+///
+/// ```
+/// use ov::*;
+/// use pin_project::pin_project;
+/// use std::future::Future;
+/// use std::pin::{pin, Pin};
+/// use std::task::{Context, Poll, Waker};
+///
+/// #[pin_project]
+/// struct Counted<F> {
+///     #[pin]
+///     inner: F,
+///     polls: u32,
+/// }
+///
+/// impl<F: Future> Future for Counted<F> {
+///     type Output = (F::Output, u32);
+///
+///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+///         over_project!(self => |this| {
+///             *this.polls += 1;
+///             let polls = *this.polls;
+///             this.inner.poll(cx).map(|out| (out, polls))
+///         })
+///     }
+/// }
+///
+/// let fut = pin!(Counted { inner: async { 5 }, polls: 0 });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(fut.poll(&mut cx), Poll::Ready((5, 1)));
+/// ```
+#[macro_export]
+macro_rules! over_project {
+    (ref $pinned:expr => |$this:pat_param| $body:expr) => {{
+        let $this = $pinned.project_ref();
+        $body
+    }};
+    (ref $pinned:expr => $f:expr) => {
+        ($f)($pinned.project_ref())
+    };
+    ($pinned:expr => |$this:pat_param| $body:expr) => {{
+        let $this = $pinned.project();
+        $body
+    }};
+    ($pinned:expr => $f:expr) => {
+        ($f)($pinned.project())
+    };
+}
@@ -0,0 +1,82 @@
+//! Property tests of pipelines with `proptest`.
+//!
+//! ```
+//! use ov::property::prop_assert_pipeline;
+//! use ov::*;
+//! use proptest::prelude::*;
+//!
+//! fn normalize(tags: Vec<String>) -> Vec<String> {
+//!     tags.over(|mut v| {
+//!         v.iter_mut().for_each(|t| *t = t.to_lowercase());
+//!         v.sort();
+//!         v.dedup();
+//!         v
+//!     })
+//! }
+//!
+//! prop_assert_pipeline(prop::collection::vec("[a-zA-Z]{1,3}", 0..8), normalize, |input, out| {
+//!     prop_assert!(out.len() <= input.len());
+//!     out.over_prop_assert(|v| v.windows(2).all(|w| w[0] < w[1]), "sorted and unique")?;
+//!     Ok(())
+//! });
+//! ```
+
+use core::fmt::Debug;
+use proptest::strategy::Strategy;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+
+/// Check a pipeline on generated inputs, panicking with the smallest failing input
+///
+/// `check` receives each input along with the pipeline's output for it, and can use
+/// `prop_assert!` or [`over_prop_assert`](trait.OverPropAssert.html). Failures are shrunk
+/// before they are reported, but not persisted to a regressions file.
+#[track_caller]
+pub fn prop_assert_pipeline<S, P, C, Out>(strategy: S, pipeline: P, check: C)
+where
+    S: Strategy,
+    S::Value: Clone,
+    P: Fn(S::Value) -> Out,
+    C: Fn(&S::Value, &Out) -> Result<(), TestCaseError>,
+{
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let result = TestRunner::new(config).run(&strategy, |input| {
+        let out = pipeline(input.clone());
+        check(&input, &out)
+    });
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
+
+/// Check the value, passing it through on success, as a `proptest` failure otherwise
+/// (`pred(&self)? |> self`)
+///
+/// The failure message includes the value, and returning it as an error rather than
+/// panicking lets `proptest` shrink the input cleanly.
+///
+/// ```
+/// use ov::*;
+///
+/// let ok = 4.over_prop_assert(|n| n % 2 == 0, "even");
+/// assert_eq!(ok.unwrap(), 4);
+///
+/// let err = 3.over_prop_assert(|n| n % 2 == 0, "even").unwrap_err();
+/// assert!(err.to_string().contains("even: 3"));
+/// ```
+pub trait OverPropAssert: Debug + Sized {
+    fn over_prop_assert<F>(self, pred: F, message: &str) -> Result<Self, TestCaseError>
+    where
+        F: FnOnce(&Self) -> bool,
+    {
+        if pred(&self) {
+            Ok(self)
+        } else {
+            Err(TestCaseError::fail(format!("{}: {:?}", message, self)))
+        }
+    }
+}
+
+impl<T> OverPropAssert for T where T: Debug {}
@@ -0,0 +1,49 @@
+//! Short-circuiting stages built on the unstable `Try` trait.
+
+use core::ops::{ControlFlow, FromResidual, Try};
+
+/// Transform the output of `?` (`self? |> f`)
+///
+/// Short-circuits on `None`, `Err`, or any other residual in the same way that `?` would,
+/// including the `From` conversion of errors. Requires the `nightly` feature.
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(Some(5u32).over_q(|n| n.checked_sub(2)), Some(3));
+/// assert_eq!(Some(1u32).over_q(|n| n.checked_sub(2)), None);
+/// assert_eq!(None.over_q(|n: u32| n.checked_sub(2)), None);
+///
+/// let parsed: Result<u8, Box<dyn std::error::Error>> = "x".parse::<u8>().over_q(Ok);
+/// assert!(parsed.is_err());
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `Try`, so `over_q` can't apply `?` to it",
+        label = "not `Try`",
+        note = "use `over` to transform the value as a whole"
+    )
+)]
+pub trait OverQ: Try {
+    fn over_q<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Self::Output) -> R,
+        R: FromResidual<Self::Residual>;
+}
+
+impl<T> OverQ for T
+where
+    T: Try,
+{
+    fn over_q<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(Self::Output) -> R,
+        R: FromResidual<Self::Residual>,
+    {
+        match self.branch() {
+            ControlFlow::Continue(output) => f(output),
+            ControlFlow::Break(residual) => R::from_residual(residual),
+        }
+    }
+}
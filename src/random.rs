@@ -0,0 +1,64 @@
+//! Stages which receive a random number generator.
+
+use crate::WithState;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+/// Transform with the thread-local random number generator (`(self, &mut rand::rng())`)
+///
+/// ```
+/// use ov::*;
+/// use rand::Rng;
+///
+/// let jittered = 100.over_with_rng(|n, rng| n + rng.random_range(0..10));
+/// assert!((100..110).contains(&jittered));
+/// ```
+pub trait OverWithRng: Sized {
+    fn over_with_rng<F, Ret>(self, f: F) -> Ret
+    where
+        F: FnOnce(Self, &mut ThreadRng) -> Ret,
+    {
+        f(self, &mut rand::rng())
+    }
+}
+
+impl<T> OverWithRng for T {}
+
+/// Start a pipeline with a random number generator (`self |> WithState::new(self, rng)`)
+///
+/// The generator is supplied once and passed to every stage, so a seeded generator makes
+/// the whole pipeline deterministic in tests.
+///
+/// ```
+/// use ov::*;
+/// use rand::rngs::StdRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// fn generate(rng: &mut StdRng) -> Vec<u8> {
+///     Vec::new()
+///         .over_rng(rng)
+///         .over(|mut v, rng| {
+///             v.push(rng.random());
+///             v
+///         })
+///         .over(|mut v, rng| {
+///             v.push(rng.random());
+///             v
+///         })
+///         .into_value()
+/// }
+///
+/// let a = generate(&mut StdRng::seed_from_u64(7));
+/// let b = generate(&mut StdRng::seed_from_u64(7));
+/// assert_eq!(a, b);
+/// ```
+pub trait OverRng: Sized {
+    fn over_rng<R>(self, rng: &mut R) -> WithState<&mut R, Self>
+    where
+        R: Rng + ?Sized,
+    {
+        WithState::new(self, rng)
+    }
+}
+
+impl<T> OverRng for T {}
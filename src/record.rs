@@ -0,0 +1,210 @@
+//! Recording the inputs and outputs of stages, and replaying them in tests.
+//!
+//! With only the `audit` feature, this module only has the [`Entry`](struct.Entry.html)
+//! which audit sinks receive.
+
+#[cfg(feature = "record")]
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(feature = "record")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// One run of a recorded stage
+///
+/// Values which fail to serialize are recorded as `null`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub stage: String,
+    pub input: Value,
+    pub output: Value,
+}
+
+/// A destination for recorded entries
+#[cfg(feature = "record")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a record sink",
+        label = "not a `RecordSink`",
+        note = "use a `Recorder`, or implement `RecordSink` to receive entries"
+    )
+)]
+pub trait RecordSink {
+    fn record(&self, entry: Entry);
+}
+
+#[cfg(feature = "record")]
+impl<S> RecordSink for &S
+where
+    S: RecordSink + ?Sized,
+{
+    fn record(&self, entry: Entry) {
+        S::record(self, entry);
+    }
+}
+
+/// An in-memory [`RecordSink`](trait.RecordSink.html), which can also replay what it recorded
+///
+/// ```
+/// use ov::*;
+///
+/// let recorder = Recorder::new();
+///
+/// let total = vec![1, 2, 3]
+///     .over_recorded(&recorder, "sum", |v| v.iter().sum::<i32>())
+///     .over_recorded(&recorder, "double", |n| n * 2);
+/// assert_eq!(total, 12);
+///
+/// // Save the recording, e.g. to a file, and load it in a test.
+/// let saved = recorder.to_json_lines();
+/// let loaded = Recorder::from_json_lines(&saved).unwrap();
+///
+/// // The replayed stage got an off-by-one bug.
+/// let mismatches = loaded.replay("double", |n: i32| n * 2 + 1).unwrap();
+/// assert_eq!(mismatches.len(), 1);
+/// assert_eq!(mismatches[0].expected, 12);
+/// assert_eq!(mismatches[0].actual, 13);
+/// ```
+#[cfg(feature = "record")]
+#[derive(Debug, Default)]
+pub struct Recorder {
+    entries: Mutex<Vec<Entry>>,
+}
+
+#[cfg(feature = "record")]
+impl Recorder {
+    pub fn new() -> Self {
+        Recorder::default()
+    }
+
+    pub fn from_entries(entries: Vec<Entry>) -> Self {
+        Recorder {
+            entries: Mutex::new(entries),
+        }
+    }
+
+    /// Load entries saved by [`to_json_lines`](#method.to_json_lines)
+    pub fn from_json_lines(lines: &str) -> serde_json::Result<Self> {
+        lines
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<serde_json::Result<Vec<Entry>>>()
+            .map(Recorder::from_entries)
+    }
+
+    /// The recorded entries, one JSON object per line
+    pub fn to_json_lines(&self) -> String {
+        self.lock()
+            .iter()
+            .map(|entry| serde_json::to_string(entry).unwrap_or_default() + "\n")
+            .collect()
+    }
+
+    /// The recorded entries, in the order they were recorded
+    pub fn entries(&self) -> Vec<Entry> {
+        self.lock().clone()
+    }
+
+    /// Run `f` on every recorded input of `stage`, returning the runs whose output differs
+    /// from the recorded output
+    ///
+    /// An error is returned if a recorded input can't be deserialized as a `T`. The entries
+    /// are copied before replaying, so `f` can record into the same recorder; its new entries
+    /// aren't replayed.
+    ///
+    /// ```
+    /// use ov::*;
+    ///
+    /// let recorder = Recorder::new();
+    /// let square = |n: i32| n.over_recorded(&recorder, "square", |n| n * n);
+    /// square(3);
+    ///
+    /// let mismatches = recorder.replay("square", square).unwrap();
+    /// assert!(mismatches.is_empty());
+    /// assert_eq!(recorder.entries().len(), 2);
+    /// ```
+    pub fn replay<T, R, F>(&self, stage: &str, mut f: F) -> serde_json::Result<Vec<Mismatch>>
+    where
+        T: DeserializeOwned,
+        R: Serialize,
+        F: FnMut(T) -> R,
+    {
+        let mut mismatches = Vec::new();
+        for (index, entry) in self.entries().iter().enumerate() {
+            if entry.stage != stage {
+                continue;
+            }
+            let input = T::deserialize(&entry.input)?;
+            let actual = to_value(&f(input));
+            if actual != entry.output {
+                mismatches.push(Mismatch {
+                    index,
+                    input: entry.input.clone(),
+                    expected: entry.output.clone(),
+                    actual,
+                });
+            }
+        }
+        Ok(mismatches)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Entry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "record")]
+impl RecordSink for Recorder {
+    fn record(&self, entry: Entry) {
+        self.lock().push(entry);
+    }
+}
+
+/// A replayed run whose output differs from the recording
+#[cfg(feature = "record")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// The position of the entry in the recording
+    pub index: usize,
+    pub input: Value,
+    pub expected: Value,
+    pub actual: Value,
+}
+
+/// Transform by value, recording the input and output in a sink (`self |> f`)
+#[cfg(feature = "record")]
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be recorded because it doesn't implement `Serialize`",
+        label = "not `Serialize`",
+        note = "derive `serde::Serialize` for the input of the stage"
+    )
+)]
+pub trait OverRecorded: Serialize + Sized {
+    fn over_recorded<S, F, Ret>(self, sink: &S, stage: &str, f: F) -> Ret
+    where
+        S: RecordSink + ?Sized,
+        F: FnOnce(Self) -> Ret,
+        Ret: Serialize,
+    {
+        let input = to_value(&self);
+        let output = f(self);
+        sink.record(Entry {
+            stage: stage.to_owned(),
+            input,
+            output: to_value(&output),
+        });
+        output
+    }
+}
+
+#[cfg(feature = "record")]
+impl<T> OverRecorded for T where T: Serialize {}
+
+#[cfg(feature = "record")]
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
@@ -0,0 +1,83 @@
+//! Recovery stages for `Result` receivers.
+
+/// Recover from an error by mapping it to a success value (`Err(e) |> f(e)`)
+///
+/// ```
+/// use ov::*;
+///
+/// let n = "x".parse::<i32>().over_recover(|_| -1);
+/// assert_eq!(n, -1);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_recover` is only implemented for `Result`, not `{Self}`",
+        label = "not a `Result`",
+        note = "for an `Option`, use `unwrap_or_else`"
+    )
+)]
+pub trait OverRecover<T, E> {
+    fn over_recover<F>(self, f: F) -> T
+    where
+        F: FnOnce(E) -> T;
+}
+
+impl<T, E> OverRecover<T, E> for Result<T, E> {
+    fn over_recover<F>(self, f: F) -> T
+    where
+        F: FnOnce(E) -> T,
+    {
+        match self {
+            Ok(value) => value,
+            Err(error) => f(error),
+        }
+    }
+}
+
+/// Attempt to recover from an error, producing a new `Result` (`Err(e) |> f(e)?`)
+///
+/// ```
+/// use ov::*;
+///
+/// let n = "x"
+///     .parse::<i32>()
+///     .over_or_else_with(|_| i32::from_str_radix("10", 16))
+///     .over_or_else_with(|_| Err::<i32, &str>("not a number"));
+/// assert_eq!(n, Ok(16));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_or_else_with` is only implemented for `Result`, not `{Self}`",
+        label = "not a `Result`",
+        note = "for an `Option`, use `or_else`"
+    )
+)]
+pub trait OverOrElseWith<T, E> {
+    fn over_or_else_with<F, E2>(self, f: F) -> Result<T, E2>
+    where
+        F: FnOnce(E) -> Result<T, E2>;
+}
+
+impl<T, E> OverOrElseWith<T, E> for Result<T, E> {
+    fn over_or_else_with<F, E2>(self, f: F) -> Result<T, E2>
+    where
+        F: FnOnce(E) -> Result<T, E2>,
+    {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => f(error),
+        }
+    }
+}
+
+async_twin! {
+    /// Recover from the error of an awaited result (`self.await |> over_recover(f)`)
+    OverRecoverAwait<T, E> for OverRecover::over_recover => over_recover_await
+        <F>(f: F) -> T
+        where { F: FnOnce(E) -> T };
+    /// Replace the error of an awaited result (`self.await |> over_or_else_with(f)`)
+    OverOrElseWithAwait<T, E> for OverOrElseWith::over_or_else_with => over_or_else_with_await
+        <F, E2>(f: F) -> Result<T, E2>
+        where { F: FnOnce(E) -> Result<T, E2> };
+}
@@ -0,0 +1,46 @@
+//! Repetition of fallible stages.
+
+/// Retry a fallible transformation (`&mut self |> f`, up to `attempts` times)
+///
+/// The closure runs at least once, even if `attempts` is zero. The first `Ok` is returned,
+/// or the last `Err` once the attempts are used up. Nothing is allocated.
+///
+/// ```
+/// use ov::*;
+///
+/// let mut sensor = vec![Err("busy"), Err("busy"), Ok(42)].into_iter();
+///
+/// let reading = sensor.over_retry(5, |s| s.next().unwrap());
+/// assert_eq!(reading, Ok(42));
+///
+/// let mut tries = 0;
+/// let failed = tries.over_retry(3, |t| {
+///     *t += 1;
+///     Err::<(), _>(*t)
+/// });
+/// assert_eq!(failed, Err(3));
+/// ```
+pub trait OverRetry {
+    fn over_retry<F, T, E>(&mut self, attempts: usize, f: F) -> Result<T, E>
+    where
+        F: FnMut(&mut Self) -> Result<T, E>;
+}
+
+impl<S> OverRetry for S
+where
+    S: ?Sized,
+{
+    fn over_retry<F, T, E>(&mut self, attempts: usize, mut f: F) -> Result<T, E>
+    where
+        F: FnMut(&mut Self) -> Result<T, E>,
+    {
+        let mut result = f(self);
+        for _ in 1..attempts {
+            if result.is_ok() {
+                break;
+            }
+            result = f(self);
+        }
+        result
+    }
+}
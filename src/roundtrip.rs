@@ -0,0 +1,71 @@
+//! Serialization round trips, for tests.
+//!
+//! ```
+//! use ov::roundtrip::Json;
+//! use ov::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct User {
+//!     name: String,
+//!     admin: bool,
+//! }
+//!
+//! let user = User { name: "ada".into(), admin: true }.assert_roundtrip::<Json>();
+//! assert!(user.admin);
+//! ```
+
+use core::fmt::Debug;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A serialization format which values can be round tripped through
+pub trait RoundtripFormat {
+    /// The name used in assertion messages
+    const NAME: &'static str;
+
+    /// Serialize the value, then deserialize the result
+    fn roundtrip<T>(value: &T) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned;
+}
+
+/// The JSON format of `serde_json`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+impl RoundtripFormat for Json {
+    const NAME: &'static str = "JSON";
+
+    fn roundtrip<T>(value: &T) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let json = serde_json::to_string(value).map_err(|e| e.to_string())?;
+        serde_json::from_str(&json).map_err(|e| e.to_string())
+    }
+}
+
+/// Assert that the value survives a round trip through a format, returning it
+/// (`assert_eq!(de(ser(&self)), self) |> self`)
+///
+/// See the [`roundtrip`](index.html) module.
+///
+/// # Panics
+///
+/// Panics if the value can't be serialized or deserialized, or comes back different.
+pub trait AssertRoundtrip: Serialize + DeserializeOwned + PartialEq + Debug + Sized {
+    #[track_caller]
+    fn assert_roundtrip<Fmt>(self) -> Self
+    where
+        Fmt: RoundtripFormat,
+    {
+        match Fmt::roundtrip(&self) {
+            Ok(back) => assert_eq!(back, self, "value changed in a {} round trip", Fmt::NAME),
+            Err(e) => panic!("{} round trip of {:?} failed: {}", Fmt::NAME, self, e),
+        }
+        self
+    }
+}
+
+impl<T> AssertRoundtrip for T where T: Serialize + DeserializeOwned + PartialEq + Debug {}
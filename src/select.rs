@@ -0,0 +1,96 @@
+//! Choosing between the receiver and another value.
+
+use core::cmp::{self, Ordering};
+
+/// Keep whichever of the receiver and `other` has the smaller key
+/// (`cmp::min_by_key(self, other, key)`)
+///
+/// On a tie the receiver wins, as with `cmp::min_by_key`.
+///
+/// ```
+/// use ov::*;
+///
+/// let shortest = "banana".over_min_with("kiwi", |s| s.len()).over_min_with("fig", |s| s.len());
+/// assert_eq!(shortest, "fig");
+///
+/// assert_eq!((1, 'a').over_min_with((1, 'b'), |p| p.0), (1, 'a'));
+/// ```
+pub trait OverMinWith: Sized {
+    fn over_min_with<F, K>(self, other: Self, key: F) -> Self
+    where
+        F: FnMut(&Self) -> K,
+        K: Ord,
+    {
+        cmp::min_by_key(self, other, key)
+    }
+}
+
+impl<T> OverMinWith for T {}
+
+/// Keep whichever of the receiver and `other` has the larger key
+/// (`cmp::max_by_key(self, other, key)`)
+///
+/// On a tie `other` wins, as with `cmp::max_by_key`, so the larger of two equal values is
+/// always the later one.
+///
+/// ```
+/// use ov::*;
+///
+/// let longest = "fig".over_max_with("kiwi", |s| s.len()).over_max_with("pear", |s| s.len());
+/// assert_eq!(longest, "pear");
+/// ```
+pub trait OverMaxWith: Sized {
+    fn over_max_with<F, K>(self, other: Self, key: F) -> Self
+    where
+        F: FnMut(&Self) -> K,
+        K: Ord,
+    {
+        cmp::max_by_key(self, other, key)
+    }
+}
+
+impl<T> OverMaxWith for T {}
+
+/// Keep whichever of the receiver and `other` is smaller by a comparison
+/// (`cmp::min_by(self, other, compare)`)
+///
+/// On a tie the receiver wins. This is the way to compare floats, which aren't `Ord`.
+///
+/// ```
+/// use ov::*;
+///
+/// let lowest = 2.5f64.over_min_by(0.5, f64::total_cmp).over_min_by(1.0, f64::total_cmp);
+/// assert_eq!(lowest, 0.5);
+/// ```
+pub trait OverMinBy: Sized {
+    fn over_min_by<F>(self, other: Self, compare: F) -> Self
+    where
+        F: FnOnce(&Self, &Self) -> Ordering,
+    {
+        cmp::min_by(self, other, compare)
+    }
+}
+
+impl<T> OverMinBy for T {}
+
+/// Keep whichever of the receiver and `other` is larger by a comparison
+/// (`cmp::max_by(self, other, compare)`)
+///
+/// On a tie `other` wins.
+///
+/// ```
+/// use ov::*;
+///
+/// let newest = ("a", 3).over_max_by(("b", 7), |x, y| x.1.cmp(&y.1));
+/// assert_eq!(newest, ("b", 7));
+/// ```
+pub trait OverMaxBy: Sized {
+    fn over_max_by<F>(self, other: Self, compare: F) -> Self
+    where
+        F: FnOnce(&Self, &Self) -> Ordering,
+    {
+        cmp::max_by(self, other, compare)
+    }
+}
+
+impl<T> OverMaxBy for T {}
@@ -0,0 +1,137 @@
+//! Stages which pass blocks of a slice.
+
+use core::slice::{Chunks, Windows};
+#[cfg(feature = "rayon")]
+use rayon::slice::{Chunks as ParChunks, ParallelSlice, Windows as ParWindows};
+
+/// Transform by non-overlapping chunks of a slice (`&self |> self.chunks(size)`)
+///
+/// The last chunk is shorter if `size` doesn't divide the length.
+///
+/// ```
+/// use ov::*;
+///
+/// let sums: Vec<i32> = vec![1, 2, 3, 4, 5].over_chunks(2, |c| c.map(|c| c.iter().sum()).collect());
+/// assert_eq!(sums, [3, 7, 5]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+pub trait OverChunks<T> {
+    fn over_chunks<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(Chunks<'a, T>) -> Ret;
+}
+
+impl<T> OverChunks<T> for [T] {
+    fn over_chunks<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(Chunks<'a, T>) -> Ret,
+    {
+        f(self.chunks(size))
+    }
+}
+
+/// Transform by overlapping windows of a slice (`&self |> self.windows(size)`)
+///
+/// ```
+/// use ov::*;
+///
+/// let rising = [1, 3, 2, 5].over_windows(2, |w| w.filter(|w| w[0] < w[1]).count());
+/// assert_eq!(rising, 2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+pub trait OverWindows<T> {
+    fn over_windows<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(Windows<'a, T>) -> Ret;
+}
+
+impl<T> OverWindows<T> for [T] {
+    fn over_windows<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(Windows<'a, T>) -> Ret,
+    {
+        f(self.windows(size))
+    }
+}
+
+/// Transform by non-overlapping chunks of a slice, as a parallel iterator
+/// (`&self |> self.par_chunks(size)`)
+///
+/// ```
+/// use ov::*;
+/// use rayon::prelude::*;
+///
+/// let sums: Vec<i32> = vec![1, 2, 3, 4, 5].over_par_chunks(2, |c| c.map(|c| c.iter().sum()).collect());
+/// assert_eq!(sums, [3, 7, 5]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+#[cfg(feature = "rayon")]
+pub trait OverParChunks<T> {
+    fn over_par_chunks<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(ParChunks<'a, T>) -> Ret;
+}
+
+#[cfg(feature = "rayon")]
+impl<T> OverParChunks<T> for [T]
+where
+    T: Sync,
+{
+    fn over_par_chunks<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(ParChunks<'a, T>) -> Ret,
+    {
+        f(self.par_chunks(size))
+    }
+}
+
+/// Transform by overlapping windows of a slice, as a parallel iterator
+/// (`&self |> self.par_windows(size)`)
+///
+/// ```
+/// use ov::*;
+/// use rayon::prelude::*;
+///
+/// let rising = [1, 3, 2, 5].over_par_windows(2, |w| w.filter(|w| w[0] < w[1]).count());
+/// assert_eq!(rising, 2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+#[cfg(feature = "rayon")]
+pub trait OverParWindows<T> {
+    fn over_par_windows<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(ParWindows<'a, T>) -> Ret;
+}
+
+#[cfg(feature = "rayon")]
+impl<T> OverParWindows<T> for [T]
+where
+    T: Sync,
+{
+    fn over_par_windows<'a, F, Ret>(&'a self, size: usize, f: F) -> Ret
+    where
+        T: 'a,
+        F: FnOnce(ParWindows<'a, T>) -> Ret,
+    {
+        f(self.par_windows(size))
+    }
+}
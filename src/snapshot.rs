@@ -0,0 +1,35 @@
+//! Snapshot assertions on the value in the middle of a chain.
+
+/// A closure which snapshot-asserts the `Debug` output of its argument with `insta`, and
+/// returns it
+///
+/// `tap_snapshot!("name")` checks against a named snapshot file, and
+/// `tap_snapshot!(@"...")` against an inline snapshot. This is a macro rather than a method
+/// because `insta` locates snapshots from the calling test, which a method in this crate
+/// can't see. The crate using it doesn't need to depend on `insta` itself.
+///
+/// ```
+/// use ov::*;
+///
+/// let total = vec![1, 2, 3]
+///     .into_iter()
+///     .sum::<i32>()
+///     .over(tap_snapshot!(@"6"))
+///     .over(|n| n * 2);
+/// assert_eq!(total, 12);
+/// ```
+#[macro_export]
+macro_rules! tap_snapshot {
+    (@$snapshot:literal) => {
+        |value| {
+            $crate::__insta::assert_debug_snapshot!(value, @$snapshot);
+            value
+        }
+    };
+    ($name:expr) => {
+        |value| {
+            $crate::__insta::assert_debug_snapshot!($name, value);
+            value
+        }
+    };
+}
@@ -0,0 +1,107 @@
+//! Recording of intermediate values for tests.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A shared recorder of values, for asserting on the middle of a pipeline in tests
+///
+/// Clones of a `Spy` share their recorded values, and can be sent to other threads.
+///
+/// ```
+/// use ov::*;
+///
+/// let spy = Spy::new();
+///
+/// let result = vec![3, 1, 2]
+///     .into_iter()
+///     .map(|n| n * 2)
+///     .sum::<i32>()
+///     .tap_spy(&spy)
+///     .over(|n| n + 1);
+///
+/// assert_eq!(result, 13);
+/// assert_eq!(spy.values(), [12]);
+/// ```
+#[derive(Debug)]
+pub struct Spy<T> {
+    values: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T> Spy<T> {
+    pub fn new() -> Self {
+        Spy {
+            values: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Record a value
+    pub fn record(&self, value: T) {
+        self.lock().push(value);
+    }
+
+    /// The recorded values, in the order they were recorded
+    pub fn values(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.lock().clone()
+    }
+
+    /// The most recently recorded value
+    pub fn last(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.lock().last().cloned()
+    }
+
+    /// Remove and return the recorded values
+    pub fn take(&self) -> Vec<T> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    // A panic while recording can't leave the list in a broken state, so poisoning is
+    // ignored. This keeps spies usable in tests which expect a panic.
+    fn lock(&self) -> MutexGuard<'_, Vec<T>> {
+        self.values.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Clone for Spy<T> {
+    fn clone(&self) -> Self {
+        Spy {
+            values: Arc::clone(&self.values),
+        }
+    }
+}
+
+impl<T> Default for Spy<T> {
+    fn default() -> Self {
+        Spy::new()
+    }
+}
+
+/// Record a clone of the value in a spy (`self |> spy.record(self.clone())`)
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be recorded by `tap_spy` because it doesn't implement `Clone`",
+        label = "not `Clone`",
+        note = "record a reference or a derived value with `Spy::record` instead"
+    )
+)]
+pub trait TapSpy: Clone {
+    fn tap_spy(self, spy: &Spy<Self>) -> Self {
+        spy.record(self.clone());
+        self
+    }
+}
+
+impl<T> TapSpy for T where T: Clone {}
@@ -0,0 +1,91 @@
+//! Pipelines which thread a state value alongside the transformed value.
+
+/// A value together with a state which every stage can update
+///
+/// Created with [`over_state`](trait.OverState.html). Each call to
+/// [`over`](#method.over) passes the value and `&mut` state to the closure, and the
+/// returned value continues the pipeline with the same state.
+///
+/// ```
+/// use ov::*;
+///
+/// let (value, steps) = 3
+///     .over_state(0)
+///     .over(|n, steps| {
+///         *steps += 1;
+///         n * 2
+///     })
+///     .over(|n, steps| {
+///         *steps += 1;
+///         n.to_string()
+///     })
+///     .into_parts();
+///
+/// assert_eq!(value, "6");
+/// assert_eq!(steps, 2);
+/// ```
+///
+/// It converts to and from the plain [`Ov`](struct.Ov.html) wrapper, which drops the state or
+/// starts with the default one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WithState<S, T> {
+    value: T,
+    state: S,
+}
+
+impl<S, T> WithState<S, T> {
+    pub fn new(value: T, state: S) -> Self {
+        WithState { value, state }
+    }
+
+    /// Run a stage, passing it the value and the state
+    pub fn over<F, U>(self, f: F) -> WithState<S, U>
+    where
+        F: FnOnce(T, &mut S) -> U,
+    {
+        let WithState { value, mut state } = self;
+        let value = f(value, &mut state);
+        WithState { value, state }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Finish the pipeline, returning the value and the state
+    pub fn into_parts(self) -> (T, S) {
+        (self.value, self.state)
+    }
+
+    /// Finish the pipeline, discarding the state
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+/// Ends the pipeline, as with [`into_parts`](#method.into_parts)
+impl<S, T> From<WithState<S, T>> for (T, S) {
+    fn from(with_state: WithState<S, T>) -> Self {
+        with_state.into_parts()
+    }
+}
+
+/// Starts the pipeline, as with [`new`](#method.new)
+impl<S, T> From<(T, S)> for WithState<S, T> {
+    fn from((value, state): (T, S)) -> Self {
+        WithState::new(value, state)
+    }
+}
+
+/// Start a pipeline with state (`self |> WithState::new(self, state)`)
+pub trait OverState: Sized {
+    fn over_state<S>(self, state: S) -> WithState<S, Self> {
+        WithState::new(self, state)
+    }
+}
+
+impl<T> OverState for T {}
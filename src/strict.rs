@@ -0,0 +1,94 @@
+//! Opt-in versions of the base traits, for codebases which don't want them on every type.
+//!
+//! [`Over`](trait.Over.html), [`OverRef`](trait.OverRef.html) and
+//! [`OverMut`](trait.OverMut.html) here have the same methods as the root traits, but no
+//! blanket impls. They are only implemented for types which opt in with
+//! [`impl_over!`](../macro.impl_over.html) or an empty `impl`, so the methods don't show up
+//! in autocomplete on other types. They also don't collide with inherent `over` methods on
+//! types which haven't opted in.
+//!
+//! To use them instead of the root traits, import them by name. A named import shadows the
+//! glob import of the root trait with the same name, so `ov::*` can still bring in
+//! everything else. Since these are separate traits, the root traits and their blanket
+//! impls stay as they are, and other crates using them are unaffected.
+//!
+//! ```
+//! use ov::*;
+//! use ov::strict::{Over, OverMut, OverRef};
+//!
+//! struct Meters(f64);
+//! impl Over for Meters {}
+//!
+//! struct Recipe;
+//! impl Recipe {
+//!     fn over(&self) -> &'static str {
+//!         "inherent"
+//!     }
+//! }
+//!
+//! assert_eq!(Meters(2.0).over(|m| m.0 * 100.0), 200.0);
+//! assert_eq!(Recipe.over(), "inherent");
+//! assert_eq!(Some(2).over_inside(|n| n + 1), Some(3));
+//! ```
+//!
+//! Types which haven't opted in don't have the methods:
+//!
+//! ```compile_fail
+//! use ov::strict::Over;
+//!
+//! let n = 5.over(|n| n + 1);
+//! ```
+
+/// Transform by value (`self`), for types which opt in
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `ov::strict::Over`",
+        label = "hasn't opted in to `ov::strict::Over`",
+        note = "types opt in with `ov::impl_over!`"
+    )
+)]
+pub trait Over: Sized {
+    fn over<F, Ret>(self, f: F) -> Ret
+    where
+        F: FnOnce(Self) -> Ret,
+    {
+        f(self)
+    }
+}
+
+/// Transform by shared reference (`&self`), for types which opt in
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `ov::strict::OverRef`",
+        label = "hasn't opted in to `ov::strict::OverRef`",
+        note = "types opt in with `ov::impl_over!`"
+    )
+)]
+pub trait OverRef {
+    fn over_ref<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(&Self) -> Ret,
+    {
+        f(self)
+    }
+}
+
+/// Transform by unique reference (`&mut self`), for types which opt in
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't implement `ov::strict::OverMut`",
+        label = "hasn't opted in to `ov::strict::OverMut`",
+        note = "types opt in with `ov::impl_over!`"
+    )
+)]
+pub trait OverMut {
+    fn over_mut<F, Ret>(&mut self, f: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        f(self)
+    }
+}
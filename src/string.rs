@@ -0,0 +1,79 @@
+//! Stages which edit an owned `String` and return it.
+
+use alloc::string::String;
+
+/// Append a string slice and return the string (`self |> { self.push_str(s); self }`)
+///
+/// ```
+/// use ov::*;
+///
+/// let path = String::from("/api").over_push_str("/users").over_push_str("/42");
+/// assert_eq!(path, "/api/users/42");
+/// ```
+pub trait OverPushStr {
+    fn over_push_str(self, s: &str) -> Self;
+}
+
+impl OverPushStr for String {
+    fn over_push_str(mut self, s: &str) -> Self {
+        self.push_str(s);
+        self
+    }
+}
+
+/// Remove leading and trailing whitespace in place and return the string
+/// (`self |> self.trim().to_owned()`)
+///
+/// The string keeps its allocation, unlike `trim().to_owned()`.
+///
+/// ```
+/// use ov::*;
+///
+/// let name = String::from("  Ada Lovelace\n");
+/// let capacity = name.capacity();
+/// let name = name.over_trimmed();
+/// assert_eq!(name, "Ada Lovelace");
+/// assert_eq!(name.capacity(), capacity);
+/// ```
+pub trait OverTrimmed {
+    fn over_trimmed(self) -> Self;
+}
+
+impl OverTrimmed for String {
+    fn over_trimmed(mut self) -> Self {
+        self.truncate(self.trim_end().len());
+        let start = self.len() - self.trim_start().len();
+        self.drain(..start);
+        self
+    }
+}
+
+/// Replace every match of `from` with `to` and return the string
+/// (`self |> self.replace(from, to)`)
+///
+/// The string is returned as it is when `from` doesn't occur in it, without copying.
+///
+/// ```
+/// use ov::*;
+///
+/// let slug = String::from("  Hello World  ")
+///     .over_trimmed()
+///     .over_replaced(" ", "-")
+///     .over_push_str(".html");
+/// assert_eq!(slug, "Hello-World.html");
+///
+/// assert_eq!(String::from("abc").over_replaced("x", "y"), "abc");
+/// ```
+pub trait OverReplaced {
+    fn over_replaced(self, from: &str, to: &str) -> Self;
+}
+
+impl OverReplaced for String {
+    fn over_replaced(self, from: &str, to: &str) -> Self {
+        if self.contains(from) {
+            self.replace(from, to)
+        } else {
+            self
+        }
+    }
+}
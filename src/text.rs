@@ -0,0 +1,135 @@
+//! Stages which pass the parts of a string.
+
+use core::str::Lines;
+#[cfg(feature = "regex")]
+use regex::{CaptureMatches, Captures, Regex};
+#[cfg(feature = "unicode")]
+use unicode_segmentation::{Graphemes, UnicodeSegmentation, UnicodeWords};
+
+/// Transform by the lines of a string (`&self |> self.as_ref().lines()`)
+///
+/// ```
+/// use ov::*;
+///
+/// let text = String::from("b\na\n\nc\n");
+/// let sorted = text.over_lines(|lines| {
+///     let mut lines: Vec<_> = lines.filter(|l| !l.is_empty()).collect();
+///     lines.sort();
+///     lines.join("\n")
+/// });
+/// assert_eq!(sorted, "a\nb\nc");
+/// ```
+pub trait OverLines: AsRef<str> {
+    fn over_lines<'a, F, Ret>(&'a self, f: F) -> Ret
+    where
+        F: FnOnce(Lines<'a>) -> Ret,
+    {
+        f(self.as_ref().lines())
+    }
+}
+
+impl<T> OverLines for T where T: AsRef<str> + ?Sized {}
+
+/// Transform by the extended grapheme clusters of a string
+/// (`&self |> self.as_ref().graphemes(true)`)
+///
+/// ```
+/// use ov::*;
+///
+/// let flag = "🇫🇷e\u{301}";
+/// assert_eq!(flag.chars().count(), 4);
+/// assert_eq!(flag.over_graphemes(|g| g.count()), 2);
+/// assert_eq!(flag.over_graphemes(|g| g.rev().collect::<String>()), "e\u{301}🇫🇷");
+/// ```
+#[cfg(feature = "unicode")]
+pub trait OverGraphemes: AsRef<str> {
+    fn over_graphemes<'a, F, Ret>(&'a self, f: F) -> Ret
+    where
+        F: FnOnce(Graphemes<'a>) -> Ret,
+    {
+        f(self.as_ref().graphemes(true))
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<T> OverGraphemes for T where T: AsRef<str> + ?Sized {}
+
+/// Transform by the words of a string, as defined by Unicode
+/// (`&self |> self.as_ref().unicode_words()`)
+///
+/// Punctuation and whitespace between the words are skipped.
+///
+/// ```
+/// use ov::*;
+///
+/// let words = "Hello, wörld! It's 3.5°C.".over_words(|w| w.collect::<Vec<_>>());
+/// assert_eq!(words, ["Hello", "wörld", "It's", "3.5", "C"]);
+/// ```
+#[cfg(feature = "unicode")]
+pub trait OverWords: AsRef<str> {
+    fn over_words<'a, F, Ret>(&'a self, f: F) -> Ret
+    where
+        F: FnOnce(UnicodeWords<'a>) -> Ret,
+    {
+        f(self.as_ref().unicode_words())
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<T> OverWords for T where T: AsRef<str> + ?Sized {}
+
+/// Transform by the captures of the first match of a regex, if any
+/// (`&self |> re.captures(self.as_ref())`)
+///
+/// ```
+/// use ov::*;
+/// use regex::Regex;
+///
+/// let re = Regex::new(r"(?P<key>\w+)=(?P<value>\d+)").unwrap();
+///
+/// let port = "host=a port=8080".over_captures(&re, |caps| {
+///     caps.and_then(|c| c["value"].parse::<u16>().ok())
+/// });
+/// assert_eq!(port, Some(8080));
+///
+/// assert!("no pairs".over_captures(&re, |caps| caps.is_none()));
+/// ```
+#[cfg(feature = "regex")]
+pub trait OverCaptures: AsRef<str> {
+    fn over_captures<'a, F, Ret>(&'a self, re: &Regex, f: F) -> Ret
+    where
+        F: FnOnce(Option<Captures<'a>>) -> Ret,
+    {
+        f(re.captures(self.as_ref()))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T> OverCaptures for T where T: AsRef<str> + ?Sized {}
+
+/// Transform by the captures of every match of a regex
+/// (`&self |> re.captures_iter(self.as_ref())`)
+///
+/// ```
+/// use ov::*;
+/// use regex::Regex;
+///
+/// let re = Regex::new(r"(\w+)=(\d+)").unwrap();
+///
+/// let pairs: Vec<(String, u32)> = "a=1, b=22, c=x".over_captures_iter(&re, |all| {
+///     all.map(|c| (c[1].to_owned(), c[2].parse().unwrap())).collect()
+/// });
+/// assert_eq!(pairs, [("a".to_owned(), 1), ("b".to_owned(), 22)]);
+/// ```
+#[cfg(feature = "regex")]
+pub trait OverCapturesIter: AsRef<str> {
+    fn over_captures_iter<'r, 'a, F, Ret>(&'a self, re: &'r Regex, f: F) -> Ret
+    where
+        F: FnOnce(CaptureMatches<'r, 'a>) -> Ret,
+    {
+        f(re.captures_iter(self.as_ref()))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T> OverCapturesIter for T where T: AsRef<str> + ?Sized {}
@@ -0,0 +1,32 @@
+//! Stages which spawn threads.
+
+use std::thread::{self, Scope};
+
+/// Transform by value inside `std::thread::scope` (`thread::scope(|s| f(self, s))`)
+///
+/// Threads spawned on the scope may borrow from the surrounding code, and are joined
+/// before the stage returns.
+///
+/// ```
+/// use ov::*;
+///
+/// let words = ["a", "bb", "ccc"];
+/// let lens = vec![0; 3].over_scoped(|mut lens, s| {
+///     let handles: Vec<_> = words.iter().map(|w| s.spawn(move || w.len())).collect();
+///     for (len, handle) in lens.iter_mut().zip(handles) {
+///         *len = handle.join().unwrap();
+///     }
+///     lens
+/// });
+/// assert_eq!(lens, [1, 2, 3]);
+/// ```
+pub trait OverScoped: Sized {
+    fn over_scoped<'env, F, Ret>(self, f: F) -> Ret
+    where
+        F: for<'scope> FnOnce(Self, &'scope Scope<'scope, 'env>) -> Ret,
+    {
+        thread::scope(|s| f(self, s))
+    }
+}
+
+impl<T> OverScoped for T {}
@@ -0,0 +1,81 @@
+//! All-or-nothing mutation.
+
+/// Puts the snapshot back when dropped, unless the mutation committed
+struct Rollback<'a, T> {
+    value: &'a mut T,
+    snapshot: Option<T>,
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            *self.value = snapshot;
+        }
+    }
+}
+
+/// Transform by unique reference, restoring the original value if the closure fails
+/// (`&mut self |> f(self)?`, or `*self = snapshot`)
+///
+/// The value is cloned before `f` runs. If `f` returns `Err`, the clone is put back and the
+/// error is returned. If `f` panics, the clone is put back while unwinding, so a caught panic
+/// also leaves the value as it was.
+///
+/// ```
+/// use ov::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Config {
+///     port: u16,
+///     hosts: Vec<&'static str>,
+/// }
+///
+/// let mut config = Config { port: 80, hosts: vec!["a"] };
+///
+/// let res = config.over_mut_transactional(|c| {
+///     c.hosts.push("b");
+///     c.port = "99999".parse().map_err(|_| "bad port")?;
+///     Ok(())
+/// });
+/// assert_eq!(res, Err("bad port"));
+/// assert_eq!(config, Config { port: 80, hosts: vec!["a"] });
+///
+/// let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+///     config.over_mut_transactional(|c| -> Result<(), ()> {
+///         c.hosts.clear();
+///         panic!("oops");
+///     })
+/// }));
+/// assert!(res.is_err());
+/// assert_eq!(config.hosts, ["a"]);
+///
+/// let len = config.over_mut_transactional(|c| {
+///     c.hosts.push("b");
+///     Ok::<_, ()>(c.hosts.len())
+/// });
+/// assert_eq!(len, Ok(2));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be mutated transactionally because it doesn't implement `Clone`",
+        label = "not `Clone`",
+        note = "the value is cloned so it can be restored if the closure fails"
+    )
+)]
+pub trait OverMutTransactional: Clone {
+    fn over_mut_transactional<F, Ret, E>(&mut self, f: F) -> Result<Ret, E>
+    where
+        F: FnOnce(&mut Self) -> Result<Ret, E>,
+    {
+        let mut rollback = Rollback {
+            snapshot: Some(self.clone()),
+            value: self,
+        };
+        let ret = f(rollback.value)?;
+        rollback.snapshot = None;
+        Ok(ret)
+    }
+}
+
+impl<T> OverMutTransactional for T where T: Clone {}
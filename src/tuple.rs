@@ -0,0 +1,72 @@
+//! Transformation of one element of a pair.
+
+/// Transform the first element of a pair (`(a, b) |> (f(a), b)`)
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!((2, "b").over_first(|n| n * 10), (20, "b"));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_first` is only implemented for pairs, not `{Self}`",
+        label = "not a pair",
+        note = "use `over` to transform the value as a whole"
+    )
+)]
+pub trait OverFirst<A, B> {
+    fn over_first<F, Ret>(self, f: F) -> (Ret, B)
+    where
+        F: FnOnce(A) -> Ret;
+}
+
+impl<A, B> OverFirst<A, B> for (A, B) {
+    fn over_first<F, Ret>(self, f: F) -> (Ret, B)
+    where
+        F: FnOnce(A) -> Ret,
+    {
+        (f(self.0), self.1)
+    }
+}
+
+/// Transform the second element of a pair (`(a, b) |> (a, f(b))`)
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!((2, "b").over_second(str::len), (2, 1));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_second` is only implemented for pairs, not `{Self}`",
+        label = "not a pair",
+        note = "use `over` to transform the value as a whole"
+    )
+)]
+pub trait OverSecond<A, B> {
+    fn over_second<F, Ret>(self, f: F) -> (A, Ret)
+    where
+        F: FnOnce(B) -> Ret;
+}
+
+impl<A, B> OverSecond<A, B> for (A, B) {
+    fn over_second<F, Ret>(self, f: F) -> (A, Ret)
+    where
+        F: FnOnce(B) -> Ret,
+    {
+        (self.0, f(self.1))
+    }
+}
+
+async_twin! {
+    /// Transform the first element of an awaited pair (`self.await |> over_first(f)`)
+    OverFirstAwait<A, B> for OverFirst::over_first => over_first_await
+        <F, Ret>(f: F) -> (Ret, B)
+        where { F: FnOnce(A) -> Ret };
+    /// Transform the second element of an awaited pair (`self.await |> over_second(f)`)
+    OverSecondAwait<A, B> for OverSecond::over_second => over_second_await
+        <F, Ret>(f: F) -> (A, Ret)
+        where { F: FnOnce(B) -> Ret };
+}
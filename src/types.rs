@@ -0,0 +1,148 @@
+//! Inspecting the type of the value in a chain.
+
+#[cfg(feature = "io")]
+use std::any::type_name;
+#[cfg(feature = "io")]
+use std::mem;
+#[cfg(feature = "io")]
+use std::panic::Location;
+
+/// Print the type of the value to stderr, returning the receiver
+/// (`eprintln!(type_name::<Self>()) |> self`)
+///
+/// The output starts with the caller's location, in the style of `dbg!`. The names come
+/// from `std::any::type_name`, so they are meant for debugging and aren't stable.
+///
+/// ```
+/// use ov::*;
+///
+/// // Prints something like "[src/main.rs:5:6] alloc::vec::Vec<(usize, char)>"
+/// let pairs = "abc"
+///     .chars()
+///     .enumerate()
+///     .collect::<Vec<_>>()
+///     .tap_type_name();
+/// assert_eq!(pairs.len(), 3);
+/// ```
+#[cfg(feature = "io")]
+pub trait TapTypeName: Sized {
+    #[track_caller]
+    fn tap_type_name(self) -> Self {
+        let caller = Location::caller();
+        eprintln!("[{}] {}", caller, type_name::<Self>());
+        self
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> TapTypeName for T {}
+
+/// Print the size and alignment of the value's type to stderr, returning the receiver
+/// (`eprintln!(size_of::<Self>(), align_of::<Self>()) |> self`)
+///
+/// Large sizes point at values which are expensive to move from stage to stage.
+///
+/// ```
+/// use ov::*;
+///
+/// // Prints something like "[src/main.rs:4:28] [u64; 512]: size 4096, align 8"
+/// let table = [0u64; 512].tap_layout();
+/// assert_eq!(table.len(), 512);
+/// ```
+#[cfg(feature = "io")]
+pub trait TapLayout: Sized {
+    #[track_caller]
+    fn tap_layout(self) -> Self {
+        let caller = Location::caller();
+        eprintln!(
+            "[{}] {}: size {}, align {}",
+            caller,
+            type_name::<Self>(),
+            mem::size_of::<Self>(),
+            mem::align_of::<Self>()
+        );
+        self
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> TapLayout for T {}
+
+/// Print the size and alignment of the value behind a reference to stderr, returning the
+/// reference (`eprintln!(size_of_val(self), align_of_val(self)) |> self`)
+///
+/// Unlike [`tap_layout`](trait.TapLayout.html), this works for unsized values such as
+/// slices and trait objects.
+///
+/// ```
+/// use ov::*;
+///
+/// let names = vec!["a", "b", "c"];
+/// // Prints something like "[src/main.rs:5:29] [&str]: size 48, align 8"
+/// let names = names.as_slice().tap_layout_val();
+/// assert_eq!(names.len(), 3);
+/// ```
+#[cfg(feature = "io")]
+pub trait TapLayoutVal {
+    #[track_caller]
+    fn tap_layout_val(&self) -> &Self {
+        let caller = Location::caller();
+        eprintln!(
+            "[{}] {}: size {}, align {}",
+            caller,
+            type_name::<Self>(),
+            mem::size_of_val(self),
+            mem::align_of_val(self)
+        );
+        self
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> TapLayoutVal for T where T: ?Sized {}
+
+/// Implemented only for `Self == U`, to assert that two types are the same
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "expected the value to be `{U}`, but it is `{Self}`",
+        label = "expected `{U}`",
+        note = "the mismatch is at this stage of the chain"
+    )
+)]
+pub trait IsSameAs<U> {}
+
+impl<T> IsSameAs<T> for T {}
+
+/// Assert the type of the value at compile time, returning the receiver (`self: U`)
+///
+/// This compiles to nothing. Pinning down types in a long chain makes inference errors
+/// show up at the stage which caused them.
+///
+/// ```
+/// use ov::*;
+///
+/// let lens = vec!["a", "bb"]
+///     .into_iter()
+///     .map(str::len)
+///     .collect::<Vec<_>>()
+///     .expect_type::<Vec<usize>>()
+///     .over(|v| v.len());
+/// assert_eq!(lens, 2);
+/// ```
+///
+/// ```compile_fail
+/// use ov::*;
+///
+/// let n = "5".parse::<u8>().expect_type::<Result<u16, std::num::ParseIntError>>();
+/// ```
+pub trait ExpectType: Sized {
+    fn expect_type<U>(self) -> Self
+    where
+        Self: IsSameAs<U>,
+    {
+        self
+    }
+}
+
+impl<T> ExpectType for T {}
@@ -0,0 +1,159 @@
+//! Initialization of `MaybeUninit<T>` slots from a closure.
+
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// Initialize by closure (`MaybeUninit<T> -> T`)
+///
+/// The closure receives the uninitialized slot and must return the reference given back
+/// by [`MaybeUninit::write`]. This is how the slot proves it was initialized: safe code has
+/// no other way to obtain a `&mut T` that points into the slot. If the returned reference
+/// points anywhere else, this panics instead of reading uninitialized memory.
+///
+/// [`MaybeUninit::write`]: https://doc.rust-lang.org/stable/std/mem/union.MaybeUninit.html#method.write
+///
+/// ```
+/// use ov::*;
+/// use std::mem::MaybeUninit;
+///
+/// let n = MaybeUninit::<u32>::uninit().over_init(|slot| slot.write(7));
+/// assert_eq!(n, 7);
+/// ```
+///
+/// The closure may also modify the value after writing it.
+///
+/// ```
+/// # use ov::*;
+/// # use std::mem::MaybeUninit;
+/// let v = MaybeUninit::uninit().over_init(|slot| {
+///     let v = slot.write(vec![1, 2]);
+///     v.push(3);
+///     v
+/// });
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_init` is only implemented for `MaybeUninit<T>`, not `{Self}`",
+        label = "not `MaybeUninit`",
+        note = "use `over_mut` to modify an initialized value"
+    )
+)]
+pub trait OverInit<T> {
+    fn over_init<F>(self, f: F) -> T
+    where
+        F: for<'a> FnOnce(&'a mut MaybeUninit<T>) -> &'a mut T;
+}
+
+impl<T> OverInit<T> for MaybeUninit<T> {
+    fn over_init<F>(mut self, f: F) -> T
+    where
+        F: for<'a> FnOnce(&'a mut MaybeUninit<T>) -> &'a mut T,
+    {
+        let slot = self.as_mut_ptr();
+        let init: *mut T = f(&mut self);
+        assert!(
+            ptr::eq(slot, init),
+            "over_init: the closure must return the reference from `MaybeUninit::write`"
+        );
+        // SAFETY: the returned reference points into `self`, and the only safe way to
+        // produce one is `MaybeUninit::write`, so the slot is initialized.
+        unsafe { self.assume_init() }
+    }
+}
+
+/// Fallibly initialize by closure (`MaybeUninit<T> -> Result<T, E>`)
+///
+/// Like [`OverInit`](trait.OverInit.html), but the closure may fail. On `Err` the slot is
+/// discarded without being read, so a value that was written before the failure is leaked
+/// rather than dropped.
+///
+/// ```
+/// use ov::*;
+/// use std::mem::MaybeUninit;
+///
+/// let ok = MaybeUninit::<u8>::uninit().try_over_init(|slot| "12".parse().map(move |n| slot.write(n)));
+/// assert_eq!(ok, Ok(12));
+///
+/// let err = MaybeUninit::<u8>::uninit().try_over_init(|slot| "x".parse().map(move |n| slot.write(n)));
+/// assert!(err.is_err());
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`try_over_init` is only implemented for `MaybeUninit<T>`, not `{Self}`",
+        label = "not `MaybeUninit`",
+        note = "use `over_mut` to modify an initialized value"
+    )
+)]
+pub trait TryOverInit<T> {
+    fn try_over_init<F, E>(self, f: F) -> Result<T, E>
+    where
+        F: for<'a> FnOnce(&'a mut MaybeUninit<T>) -> Result<&'a mut T, E>;
+}
+
+impl<T> TryOverInit<T> for MaybeUninit<T> {
+    fn try_over_init<F, E>(mut self, f: F) -> Result<T, E>
+    where
+        F: for<'a> FnOnce(&'a mut MaybeUninit<T>) -> Result<&'a mut T, E>,
+    {
+        let slot = self.as_mut_ptr();
+        let init: *mut T = f(&mut self)?;
+        assert!(
+            ptr::eq(slot, init),
+            "try_over_init: the closure must return the reference from `MaybeUninit::write`"
+        );
+        // SAFETY: see `OverInit::over_init`.
+        Ok(unsafe { self.assume_init() })
+    }
+}
+
+/// Initialize by closure without checking (`MaybeUninit<T> -> T`)
+///
+/// The closure receives the slot and initializes it by any means, typically by passing
+/// [`MaybeUninit::as_mut_ptr`] to an FFI function that fills it in.
+///
+/// [`MaybeUninit::as_mut_ptr`]: https://doc.rust-lang.org/stable/std/mem/union.MaybeUninit.html#method.as_mut_ptr
+///
+/// ```
+/// use ov::*;
+/// use std::mem::MaybeUninit;
+///
+/// unsafe fn fill(out: *mut [u8; 4]) {
+///     out.write([1, 2, 3, 4]);
+/// }
+///
+/// let bytes = unsafe {
+///     MaybeUninit::uninit().over_init_unchecked(|slot| fill(slot.as_mut_ptr()))
+/// };
+/// assert_eq!(bytes, [1, 2, 3, 4]);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`over_init_unchecked` is only implemented for `MaybeUninit<T>`, not `{Self}`",
+        label = "not `MaybeUninit`",
+        note = "use `over_mut` to modify an initialized value"
+    )
+)]
+pub trait OverInitUnchecked<T> {
+    /// # Safety
+    ///
+    /// The slot must be fully initialized when the closure returns. See
+    /// [`MaybeUninit::assume_init`](https://doc.rust-lang.org/stable/std/mem/union.MaybeUninit.html#method.assume_init).
+    /// If the closure panics the slot is discarded without being read.
+    unsafe fn over_init_unchecked<F>(self, f: F) -> T
+    where
+        F: FnOnce(&mut MaybeUninit<T>);
+}
+
+impl<T> OverInitUnchecked<T> for MaybeUninit<T> {
+    unsafe fn over_init_unchecked<F>(mut self, f: F) -> T
+    where
+        F: FnOnce(&mut MaybeUninit<T>),
+    {
+        f(&mut self);
+        self.assume_init()
+    }
+}
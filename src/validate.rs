@@ -0,0 +1,36 @@
+//! Validation of values in the middle of a chain.
+
+/// Check the value, passing it through on success (`f(&self)? |> self`)
+///
+/// ```
+/// use ov::*;
+///
+/// fn non_empty(s: &&str) -> Result<(), &'static str> {
+///     if s.is_empty() {
+///         Err("empty")
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// assert_eq!("a".over_validate(non_empty), Ok("a"));
+/// assert_eq!("".over_validate(non_empty), Err("empty"));
+/// ```
+pub trait OverValidate: Sized {
+    fn over_validate<F, E>(self, f: F) -> Result<Self, E>
+    where
+        F: FnOnce(&Self) -> Result<(), E>,
+    {
+        f(&self)?;
+        Ok(self)
+    }
+}
+
+impl<T> OverValidate for T {}
+
+async_twin! {
+    /// Validate an awaited value (`self.await |> over_validate(f)`)
+    OverValidateAwait<> for OverValidate::over_validate => over_validate_await
+        <F, E>(f: F) -> Result<Self::Output, E>
+        where { F: FnOnce(&Self::Output) -> Result<(), E> };
+}
@@ -0,0 +1,187 @@
+//! Stages which reorder an owned `Vec` or boxed slice and return it.
+//!
+//! Each stage is implemented for `Vec<T>` and `Box<[T]>`.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Sort a vector and return it (`self |> { self.sort(); self }`)
+///
+/// The sort is stable, so equal elements keep their order.
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(vec![3, 1, 2].over_sorted(), [1, 2, 3]);
+///
+/// let boxed: Box<[i32]> = Box::new([2, 3, 1]);
+/// assert_eq!(*boxed.over_sorted().over_reversed(), [3, 2, 1]);
+/// ```
+pub trait OverSorted<T> {
+    fn over_sorted(self) -> Self
+    where
+        T: Ord;
+}
+
+impl<T> OverSorted<T> for Vec<T> {
+    fn over_sorted(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.sort();
+        self
+    }
+}
+
+impl<T> OverSorted<T> for Box<[T]> {
+    fn over_sorted(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.sort();
+        self
+    }
+}
+
+/// Sort a vector by a key and return it (`self |> { self.sort_by_key(key); self }`)
+///
+/// The sort is stable, so elements with equal keys keep their order.
+///
+/// ```
+/// use ov::*;
+///
+/// let words = vec!["kiwi", "fig", "banana", "pear"].over_sorted_by_key(|w| w.len());
+/// assert_eq!(words, ["fig", "kiwi", "pear", "banana"]);
+/// ```
+pub trait OverSortedByKey<T> {
+    fn over_sorted_by_key<F, K>(self, key: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+        K: Ord;
+}
+
+impl<T> OverSortedByKey<T> for Vec<T> {
+    fn over_sorted_by_key<F, K>(mut self, key: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by_key(key);
+        self
+    }
+}
+
+impl<T> OverSortedByKey<T> for Box<[T]> {
+    fn over_sorted_by_key<F, K>(mut self, key: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by_key(key);
+        self
+    }
+}
+
+/// Remove consecutive repeated elements and return the vector
+/// (`self |> { self.dedup(); self }`)
+///
+/// Only runs of equal elements are collapsed, so sort first to remove every duplicate.
+///
+/// ```
+/// use ov::*;
+///
+/// assert_eq!(vec![1, 1, 2, 1].over_deduped(), [1, 2, 1]);
+/// assert_eq!(vec![3, 1, 3, 2, 1].over_sorted().over_deduped(), [1, 2, 3]);
+/// ```
+pub trait OverDeduped<T> {
+    fn over_deduped(self) -> Self
+    where
+        T: PartialEq;
+}
+
+impl<T> OverDeduped<T> for Vec<T> {
+    fn over_deduped(mut self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.dedup();
+        self
+    }
+}
+
+/// Shrinks the allocation when elements are removed
+impl<T> OverDeduped<T> for Box<[T]> {
+    fn over_deduped(self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.into_vec().over_deduped().into_boxed_slice()
+    }
+}
+
+/// Remove consecutive elements with the same key and return the vector
+/// (`self |> { self.dedup_by_key(key); self }`)
+///
+/// The first element of each run is kept.
+///
+/// ```
+/// use ov::*;
+///
+/// let firsts = vec!["apple", "avocado", "banana", "blueberry", "cherry"]
+///     .over_deduped_by_key(|w| w.as_bytes()[0]);
+/// assert_eq!(firsts, ["apple", "banana", "cherry"]);
+/// ```
+pub trait OverDedupedByKey<T> {
+    fn over_deduped_by_key<F, K>(self, key: F) -> Self
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq;
+}
+
+impl<T> OverDedupedByKey<T> for Vec<T> {
+    fn over_deduped_by_key<F, K>(mut self, key: F) -> Self
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by_key(key);
+        self
+    }
+}
+
+/// Shrinks the allocation when elements are removed
+impl<T> OverDedupedByKey<T> for Box<[T]> {
+    fn over_deduped_by_key<F, K>(self, key: F) -> Self
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.into_vec().over_deduped_by_key(key).into_boxed_slice()
+    }
+}
+
+/// Reverse a vector in place and return it (`self |> { self.reverse(); self }`)
+///
+/// ```
+/// use ov::*;
+///
+/// let newest_first = vec![1, 5, 3].over_sorted().over_reversed();
+/// assert_eq!(newest_first, [5, 3, 1]);
+/// ```
+pub trait OverReversed<T> {
+    fn over_reversed(self) -> Self;
+}
+
+impl<T> OverReversed<T> for Vec<T> {
+    fn over_reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+}
+
+impl<T> OverReversed<T> for Box<[T]> {
+    fn over_reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+}
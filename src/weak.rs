@@ -0,0 +1,120 @@
+//! Stages for reference-counted pointers and their weak counterparts.
+
+use alloc::rc::{self, Rc};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::{self, Arc};
+
+/// Transform by weak pointer (`&self |> Rc::downgrade(self)`)
+///
+/// Implemented for `Rc` and `Arc`.
+///
+/// ```
+/// use ov::*;
+/// use std::rc::Rc;
+///
+/// let shared = Rc::new(5);
+/// let weak = shared.over_downgrade(|weak| weak);
+/// assert_eq!(weak.upgrade(), Some(shared));
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be downgraded to a weak reference",
+        label = "not `Rc` or `Arc`",
+        note = "`over_downgrade` is implemented for `Rc<T>` and `Arc<T>`"
+    )
+)]
+pub trait OverDowngrade {
+    type Weak;
+
+    fn over_downgrade<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(Self::Weak) -> Ret;
+}
+
+impl<T: ?Sized> OverDowngrade for Rc<T> {
+    type Weak = rc::Weak<T>;
+
+    fn over_downgrade<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(rc::Weak<T>) -> Ret,
+    {
+        f(Rc::downgrade(self))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: ?Sized> OverDowngrade for Arc<T> {
+    type Weak = sync::Weak<T>;
+
+    fn over_downgrade<F, Ret>(&self, f: F) -> Ret
+    where
+        F: FnOnce(sync::Weak<T>) -> Ret,
+    {
+        f(Arc::downgrade(self))
+    }
+}
+
+/// Transform by strong pointer if it's still alive, or run `fallback` instead
+/// (`&self |> self.upgrade()`)
+///
+/// Implemented for the `Weak` pointers of `Rc` and `Arc`.
+///
+/// ```
+/// use ov::*;
+/// use std::rc::Rc;
+///
+/// let strong = Rc::new(String::from("listener"));
+/// let weak = Rc::downgrade(&strong);
+///
+/// assert_eq!(weak.over_upgrade_with(|s| s.len(), || 0), 8);
+/// drop(strong);
+/// assert_eq!(weak.over_upgrade_with(|s| s.len(), || 0), 0);
+/// ```
+#[cfg_attr(
+    ov_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` isn't a weak reference which can be upgraded",
+        label = "not a `Weak`",
+        note = "`over_upgrade_with` is implemented for `rc::Weak<T>` and `sync::Weak<T>`"
+    )
+)]
+pub trait OverUpgradeWith {
+    type Strong;
+
+    fn over_upgrade_with<F, G, Ret>(&self, f: F, fallback: G) -> Ret
+    where
+        F: FnOnce(Self::Strong) -> Ret,
+        G: FnOnce() -> Ret;
+}
+
+impl<T: ?Sized> OverUpgradeWith for rc::Weak<T> {
+    type Strong = Rc<T>;
+
+    fn over_upgrade_with<F, G, Ret>(&self, f: F, fallback: G) -> Ret
+    where
+        F: FnOnce(Rc<T>) -> Ret,
+        G: FnOnce() -> Ret,
+    {
+        match self.upgrade() {
+            Some(strong) => f(strong),
+            None => fallback(),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: ?Sized> OverUpgradeWith for sync::Weak<T> {
+    type Strong = Arc<T>;
+
+    fn over_upgrade_with<F, G, Ret>(&self, f: F, fallback: G) -> Ret
+    where
+        F: FnOnce(Arc<T>) -> Ret,
+        G: FnOnce() -> Ret,
+    {
+        match self.upgrade() {
+            Some(strong) => f(strong),
+            None => fallback(),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Stages which show up as zones through the `profiling` facade.
+
+/// A closure which runs a stage inside a profiler zone named by a literal
+///
+/// `over_profile_scope!("name", f)` is `|value| { profiling::scope!("name"); f(value) }`, so
+/// the stage appears in Tracy, puffin or Optick under its own name. The zone lasts for the
+/// duration of `f`, and does nothing unless one of the `profiling` crate's
+/// `profile-with-*` backend features is enabled. The name has to be a literal, since
+/// backends such as `tracing` and `puffin` need it at the call site; extra data, such as an
+/// id, can follow it as in `profiling::scope!`.
+///
+/// ```
+/// use ov::*;
+///
+/// let batch = 7;
+/// let total: u32 = vec![3, 1, 2]
+///     .over(over_profile_scope!("sort", |mut v: Vec<u32>| {
+///         v.sort();
+///         v
+///     }))
+///     .over(over_profile_scope!("sum", batch.to_string(), |v: Vec<u32>| v.iter().sum()));
+/// assert_eq!(total, 6);
+/// ```
+#[macro_export]
+macro_rules! over_profile_scope {
+    ($name:literal, $f:expr $(,)?) => {
+        |value| {
+            $crate::__profiling::scope!($name);
+            ($f)(value)
+        }
+    };
+    ($name:literal, $data:expr, $f:expr $(,)?) => {
+        |value| {
+            $crate::__profiling::scope!($name, $data);
+            ($f)(value)
+        }
+    };
+}
@@ -0,0 +1,92 @@
+//! Checks that `over_in_place` never drops a value twice, and aborts if the closure panics.
+
+use ov::prelude::*;
+use std::cell::Cell;
+use std::env;
+use std::process::Command;
+
+struct Tracked<'a> {
+    id: u32,
+    drops: &'a Cell<u32>,
+}
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+#[test]
+fn replaced_value_is_dropped_once() {
+    let drops = Cell::new(0);
+    let mut value = Tracked {
+        id: 1,
+        drops: &drops,
+    };
+
+    value.over_in_place(|old| {
+        assert_eq!(old.id, 1);
+        Tracked {
+            id: 2,
+            drops: old.drops,
+        }
+    });
+    assert_eq!(value.id, 2);
+    assert_eq!(drops.get(), 1);
+
+    drop(value);
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn returned_value_is_kept() {
+    let drops = Cell::new(0);
+    let mut value = Tracked {
+        id: 1,
+        drops: &drops,
+    };
+
+    value.over_in_place(|old| old).over_in_place(|mut old| {
+        old.id += 1;
+        old
+    });
+    assert_eq!(value.id, 2);
+    assert_eq!(drops.get(), 0);
+}
+
+#[test]
+fn works_through_nested_references() {
+    let mut pairs = vec![(String::from("a"), 1), (String::from("b"), 2)];
+    for pair in &mut pairs {
+        pair.over_in_place(|(s, n)| (s.repeat(n), n * 10));
+    }
+    assert_eq!(pairs, [("a".to_owned(), 10), ("bb".to_owned(), 20)]);
+}
+
+const CHILD: &str = "OV_IN_PLACE_PANIC_CHILD";
+
+#[test]
+fn panic_in_closure_aborts() {
+    if env::var_os(CHILD).is_some() {
+        let mut s = String::from("gone");
+        s.over_in_place(|_| panic!("closure failed"));
+        return;
+    }
+
+    let output = Command::new(env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "panic_in_closure_aborts",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env(CHILD, "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    // A test that merely panics exits with 101; an abort is a signal or another code.
+    assert_ne!(output.status.code(), Some(101), "{}", stderr);
+    assert!(stderr.contains("closure failed"), "{}", stderr);
+}
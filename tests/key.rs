@@ -0,0 +1,144 @@
+//! Checks that the key wrappers order, compare and hash by their key in std collections.
+
+use ov::prelude::*;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+
+#[derive(Debug, PartialEq)]
+struct Task {
+    name: &'static str,
+    priority: u8,
+}
+
+fn tasks() -> Vec<Task> {
+    vec![
+        Task {
+            name: "lint",
+            priority: 2,
+        },
+        Task {
+            name: "deploy",
+            priority: 9,
+        },
+        Task {
+            name: "test",
+            priority: 5,
+        },
+        Task {
+            name: "docs",
+            priority: 2,
+        },
+    ]
+}
+
+fn priority(task: &Task) -> u8 {
+    task.priority
+}
+
+#[test]
+fn binary_heap_pops_by_key() {
+    let mut heap: BinaryHeap<_> = tasks().into_iter().map(|t| t.ord_by(priority)).collect();
+
+    let order: Vec<u8> = std::iter::from_fn(|| heap.pop())
+        .map(|t| t.into_value().priority)
+        .collect();
+    assert_eq!(order, [9, 5, 2, 2]);
+}
+
+#[test]
+fn reverse_key_gives_a_min_heap() {
+    let mut heap: BinaryHeap<_> = tasks()
+        .into_iter()
+        .map(|t| t.ord_by(|t| std::cmp::Reverse(t.priority)))
+        .collect();
+
+    assert_eq!(heap.pop().unwrap().value().priority, 2);
+}
+
+#[test]
+fn btree_set_keeps_the_first_of_equal_keys() {
+    let mut set = BTreeSet::new();
+    for task in tasks() {
+        set.insert(task.ord_by(priority));
+    }
+
+    let names: Vec<&str> = set.iter().map(|t| t.value().name).collect();
+    assert_eq!(names, ["lint", "test", "deploy"]);
+}
+
+#[test]
+fn sort_is_stable_by_key() {
+    let mut sorted: Vec<_> = tasks().into_iter().map(|t| t.ord_by(priority)).collect();
+    sorted.sort();
+
+    let names: Vec<&str> = sorted.iter().map(|t| t.value().name).collect();
+    assert_eq!(names, ["lint", "docs", "test", "deploy"]);
+}
+
+#[test]
+fn ord_by_equality_agrees_with_ordering() {
+    let a = Task {
+        name: "a",
+        priority: 1,
+    }
+    .ord_by(priority);
+    let b = Task {
+        name: "b",
+        priority: 1,
+    }
+    .ord_by(priority);
+
+    assert_eq!(a, b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    assert_ne!(a.value(), b.value());
+}
+
+#[test]
+fn eq_by_ignores_everything_but_the_key() {
+    let by_name = |t: &Task| t.name;
+    let a = Task {
+        name: "x",
+        priority: 1,
+    }
+    .eq_by(by_name);
+    let b = Task {
+        name: "x",
+        priority: 7,
+    }
+    .eq_by(by_name);
+    let c = Task {
+        name: "y",
+        priority: 1,
+    }
+    .eq_by(by_name);
+
+    assert!(a == b);
+    assert!(a != c);
+}
+
+#[test]
+fn hash_set_dedups_by_key() {
+    let set: HashSet<_> = tasks().into_iter().map(|t| t.hash_by(priority)).collect();
+
+    let mut priorities: Vec<u8> = set.iter().map(|t| t.value().priority).collect();
+    priorities.sort();
+    assert_eq!(priorities, [2, 5, 9]);
+}
+
+#[test]
+fn hash_by_works_as_a_map_key() {
+    let by_lower = |s: &String| s.to_lowercase();
+    let mut counts = HashMap::new();
+    for word in ["Rust", "rust", "Go", "RUST"] {
+        *counts.entry(word.to_owned().hash_by(by_lower)).or_insert(0) += 1;
+    }
+
+    assert_eq!(counts[&"rUsT".to_owned().hash_by(by_lower)], 3);
+    assert_eq!(counts[&"go".to_owned().hash_by(by_lower)], 1);
+}
+
+#[test]
+fn debug_shows_only_the_value() {
+    let wrapped = 5.ord_by(|n: &i32| -n);
+    assert_eq!(format!("{:?}", wrapped), "OrdBy(5)");
+    assert_eq!(format!("{:?}", "a".hash_by(|s| s.len())), r#"HashBy("a")"#);
+}
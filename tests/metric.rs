@@ -0,0 +1,99 @@
+//! Checks that the metric stages record through the installed `metrics` recorder.
+#![cfg(feature = "metrics")]
+
+use metrics::{
+    Counter, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+};
+use ov::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct Samples(Mutex<Vec<f64>>);
+
+impl HistogramFn for Samples {
+    fn record(&self, value: f64) {
+        self.0.lock().unwrap().push(value);
+    }
+}
+
+#[derive(Default)]
+struct TestRecorder {
+    counters: Mutex<Vec<(String, Arc<AtomicU64>)>>,
+    histograms: Mutex<Vec<(String, Arc<Samples>)>>,
+}
+
+impl TestRecorder {
+    fn counter(&self, name: &str) -> u64 {
+        let counters = self.counters.lock().unwrap();
+        counters
+            .iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    fn histogram(&self, name: &str) -> Vec<f64> {
+        let histograms = self.histograms.lock().unwrap();
+        histograms
+            .iter()
+            .filter(|(key, _)| key == name)
+            .flat_map(|(_, samples)| samples.0.lock().unwrap().clone())
+            .collect()
+    }
+}
+
+impl Recorder for TestRecorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        let value = Arc::new(AtomicU64::new(0));
+        let entry = (key.name().to_owned(), Arc::clone(&value));
+        self.counters.lock().unwrap().push(entry);
+        Counter::from_arc(value)
+    }
+
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        let samples = Arc::new(Samples::default());
+        let entry = (key.name().to_owned(), Arc::clone(&samples));
+        self.histograms.lock().unwrap().push(entry);
+        Histogram::from_arc(samples)
+    }
+}
+
+#[test]
+fn counter_is_incremented_once_per_call() {
+    let recorder = TestRecorder::default();
+    let words = metrics::with_local_recorder(&recorder, || {
+        ["a", "b", "c"]
+            .map(|word| word.tap_metric_counter("words"))
+            .tap_metric_counter("batches")
+    });
+
+    assert_eq!(words, ["a", "b", "c"]);
+    assert_eq!(recorder.counter("words"), 3);
+    assert_eq!(recorder.counter("batches"), 1);
+    assert_eq!(recorder.counter("other"), 0);
+}
+
+#[test]
+fn timer_records_the_duration_in_seconds() {
+    let recorder = TestRecorder::default();
+    let doubled = metrics::with_local_recorder(&recorder, || {
+        21.over_metric_timer("double", |n| {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            n * 2
+        })
+    });
+
+    assert_eq!(doubled, 42);
+    let samples = recorder.histogram("double");
+    assert_eq!(samples.len(), 1);
+    assert!(samples[0] >= 0.01 && samples[0] < 10.0, "{:?}", samples);
+}
@@ -0,0 +1,86 @@
+//! Checks that the core tier works without `std` or `alloc`.
+//!
+//! Run with `cargo test --no-default-features --test no_std` to build the crate itself
+//! without them as well.
+#![no_std]
+
+use core::mem::MaybeUninit;
+use ov::prelude::*;
+
+#[test]
+fn base_traits() {
+    let mut n = 5.over(|n| n + 1);
+    n.over_mut(|n| *n *= 2);
+    assert_eq!(n.over_ref(|n| *n), 12);
+}
+
+#[test]
+fn option_and_result() {
+    assert_eq!(Some(2).over_inside(|n| n * 2), Some(4));
+    assert_eq!(Some(Some(1)).over_flatten(), Some(1));
+    assert_eq!(Err::<u8, u8>(3).over_recover(|e| e + 1), 4);
+    assert_eq!(
+        Err::<u8, u8>(3).over_or_else_with(|e| Err::<u8, u16>(e.into())),
+        Err(3)
+    );
+}
+
+#[test]
+fn arrays_and_tuples() {
+    assert_eq!([1, 2, 3].over_inside(|n| n * 10), [10, 20, 30]);
+
+    let mut a = [1, 2, 3];
+    a.over_each_mut(|n| *n += 1);
+    assert_eq!(a, [2, 3, 4]);
+
+    assert_eq!(
+        (1, 'x').over_first(|n| n + 1).over_second(|c| c as u32),
+        (2, 120)
+    );
+}
+
+#[test]
+fn retry_and_validate() {
+    let mut count = 0;
+    let result = count.over_retry(4, |c| {
+        *c += 1;
+        if *c < 3 {
+            Err(*c)
+        } else {
+            Ok(*c)
+        }
+    });
+    assert_eq!(result, Ok(3));
+
+    let valid = 10.over_validate(|n| if *n > 5 { Ok(()) } else { Err("small") });
+    assert_eq!(valid, Ok(10));
+}
+
+#[test]
+fn pipelines() {
+    let (value, calls) = 1
+        .over_state(0)
+        .over(|n, calls| {
+            *calls += 1;
+            n + 1
+        })
+        .into_parts();
+    assert_eq!((value, calls), (2, 1));
+
+    let scaled = 3.over_env(&10).over(|n, scale| n * scale).into_value();
+    assert_eq!(scaled, 30);
+}
+
+#[test]
+fn construction() {
+    #[derive(Default, PartialEq, Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    assert_eq!(Point::build_with(|p| p.y = 2), Point { x: 0, y: 2 });
+
+    let n = MaybeUninit::uninit().over_init(|slot| slot.write(7u8));
+    assert_eq!(n, 7);
+}
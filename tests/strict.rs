@@ -0,0 +1,55 @@
+//! Checks that the opt-in traits of `ov::strict` work alongside the glob import of `ov`.
+#![cfg(all(feature = "macros", feature = "alloc"))]
+
+use ov::prelude::*;
+use ov::strict::{Over, OverMut, OverRef};
+
+#[derive(Debug, PartialEq)]
+struct Celsius(f64);
+
+impl_over!(Celsius);
+
+struct Wrapper<T>(T);
+
+impl<T> Over for Wrapper<T> {}
+
+struct Builder {
+    parts: Vec<&'static str>,
+}
+
+impl Builder {
+    fn over(mut self, part: &'static str) -> Self {
+        self.parts.push(part);
+        self
+    }
+}
+
+#[test]
+fn opted_in_types_have_the_methods() {
+    let mut c = Celsius(20.0);
+    c.over_mut(|c| c.0 += 1.5);
+    assert_eq!(c.over_ref(|c| c.0), 21.5);
+    assert_eq!(c.over(|c| c.0 * 1.8 + 32.0), 70.7);
+}
+
+#[test]
+fn generic_types_opt_in_with_an_empty_impl() {
+    assert_eq!(Wrapper("abc").over(|w| w.0.len()), 3);
+}
+
+#[test]
+fn inherent_methods_are_not_shadowed() {
+    let b = Builder { parts: Vec::new() }.over("a").over("b");
+    assert_eq!(b.parts, ["a", "b"]);
+}
+
+#[test]
+fn other_traits_keep_their_blanket_impls() {
+    assert_eq!(Some(2).over_inside(|n| n * 3), Some(6));
+    assert_eq!(vec![3, 1, 2].over_sorted(), [1, 2, 3]);
+}
+
+#[test]
+fn root_traits_are_unchanged() {
+    assert_eq!(ov::Over::over(5, |n| n + 1), 6);
+}